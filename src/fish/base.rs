@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Fish rarity levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Rarity {
     Common,
@@ -71,6 +71,30 @@ impl std::fmt::Display for Fish {
     }
 }
 
+/// User-configurable policy for which caught fish are worth keeping, checked
+/// by `FishService::should_keep` once a catch's fish type is known. Names in
+/// `Whitelist`/`Blacklist` are matched case-insensitively against both the
+/// fish's name and id, the same lookup `FishService::get_by_name`/
+/// `get_by_id` use.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum KeepPolicy {
+    /// Keep every catch - the default, so auto-discard is opt-in.
+    KeepAll,
+    /// Discard any catch whose XP value is below the threshold.
+    KeepAbove(i32),
+    /// Discard any catch not in this list.
+    Whitelist(Vec<String>),
+    /// Discard any catch in this list.
+    Blacklist(Vec<String>),
+}
+
+impl Default for KeepPolicy {
+    fn default() -> Self {
+        KeepPolicy::KeepAll
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,10 +2,16 @@
 
 #![allow(dead_code)]
 
-use super::base::{Fish, Rarity};
+use super::base::{Fish, KeepPolicy, Rarity};
+use crate::screen_reader::base::{active_profile, get_settings, get_settings_for};
+use crate::utils::config_format::ConfigFormat;
+use parking_lot::RwLock;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
 
 /// Fish configuration file structure
 #[derive(Debug, Deserialize)]
@@ -13,75 +19,276 @@ struct FishConfig {
     fishes: Vec<Fish>,
 }
 
+/// The fish list plus indexes built once per load, so lookups by name/id/
+/// rarity are hash lookups instead of repeated linear scans with per-query
+/// `to_lowercase()` allocations.
+#[derive(Default)]
+struct FishData {
+    fishes: Vec<Fish>,
+    by_name: HashMap<String, usize>,
+    by_id: HashMap<String, usize>,
+    by_rarity: HashMap<Rarity, Vec<usize>>,
+    source_by_id: HashMap<String, PathBuf>,
+}
+
+impl FishData {
+    fn new(fishes: Vec<Fish>, source_by_id: HashMap<String, PathBuf>) -> Self {
+        let mut by_name = HashMap::with_capacity(fishes.len());
+        let mut by_id = HashMap::with_capacity(fishes.len());
+        let mut by_rarity: HashMap<Rarity, Vec<usize>> = HashMap::new();
+
+        for (idx, fish) in fishes.iter().enumerate() {
+            by_name.insert(fish.name.to_lowercase(), idx);
+            by_id.insert(fish.id.to_lowercase(), idx);
+            by_rarity.entry(fish.rarity).or_default().push(idx);
+        }
+
+        Self { fishes, by_name, by_id, by_rarity, source_by_id }
+    }
+}
+
 /// Service for managing fish data
 pub struct FishService {
-    config_path: PathBuf,
-    fishes: Vec<Fish>,
+    config_paths: Vec<PathBuf>,
+    data: Arc<RwLock<FishData>>,
 }
 
 impl FishService {
-    /// Create a new fish service
+    /// Create a new fish service backed by a single config file
     pub fn new(config_path: PathBuf) -> Self {
+        Self::from_sources(vec![config_path])
+    }
+
+    /// Create a fish service backed by an ordered list of config files. A
+    /// later source overwrites an earlier one's fish by id and may also
+    /// append new ones, so a community-maintained base list can be patched
+    /// or extended by a local override file without forking it.
+    pub fn from_sources(config_paths: Vec<PathBuf>) -> Self {
         Self {
-            config_path,
-            fishes: Vec::new(),
+            config_paths,
+            data: Arc::new(RwLock::new(FishData::default())),
         }
     }
 
-    /// Load fish data from config file
+    /// Parse a fish config file. The format (JSON/TOML/YAML/RON) is
+    /// detected from `path`'s extension.
+    fn parse_file(path: &Path) -> Result<Vec<Fish>, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        let config: FishConfig = ConfigFormat::from_path(path)
+            .parse(&content)
+            .map_err(|e| e.to_string())?;
+        Ok(config.fishes)
+    }
+
+    /// Parse every configured source in order and merge the results by fish
+    /// id: a fish from a later source overwrites an earlier one with the
+    /// same id (keeping its original position), and new ids are appended.
+    /// The first (base) source must parse successfully; later (overlay)
+    /// sources that fail to parse are logged and skipped so a broken
+    /// community override can't take down the whole fish list.
+    fn merge_sources(
+        paths: &[PathBuf],
+    ) -> Result<(Vec<Fish>, HashMap<String, PathBuf>), Box<dyn std::error::Error>> {
+        let mut fishes: Vec<Fish> = Vec::new();
+        let mut index_by_id: HashMap<String, usize> = HashMap::new();
+        let mut source_by_id: HashMap<String, PathBuf> = HashMap::new();
+
+        for (source_idx, path) in paths.iter().enumerate() {
+            let parsed = match Self::parse_file(path) {
+                Ok(parsed) => parsed,
+                Err(e) if source_idx == 0 => return Err(e),
+                Err(e) => {
+                    tracing::warn!("[FISH] Skipping unreadable overlay {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            for fish in parsed {
+                let id_lower = fish.id.to_lowercase();
+                source_by_id.insert(id_lower.clone(), path.clone());
+                match index_by_id.get(&id_lower) {
+                    Some(&idx) => fishes[idx] = fish,
+                    None => {
+                        index_by_id.insert(id_lower, fishes.len());
+                        fishes.push(fish);
+                    }
+                }
+            }
+        }
+
+        Ok((fishes, source_by_id))
+    }
+
+    /// Load fish data from the configured source(s), merging overlays in
+    /// order. Alias kept for callers that only ever had a single source.
     pub fn load_fishes(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = fs::read_to_string(&self.config_path)?;
-        let config: FishConfig = serde_json::from_str(&content)?;
-        self.fishes = config.fishes;
+        self.load_all()
+    }
+
+    /// Parse and merge every configured source, replacing the currently
+    /// loaded fish data.
+    pub fn load_all(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let (fishes, source_by_id) = Self::merge_sources(&self.config_paths)?;
+        *self.data.write() = FishData::new(fishes, source_by_id);
+        Ok(())
+    }
+
+    /// The config file a given fish id was last loaded from, for debugging
+    /// which overlay a fish's data actually came from.
+    pub fn source_of(&self, id: &str) -> Option<PathBuf> {
+        self.data.read().source_by_id.get(&id.to_lowercase()).cloned()
+    }
+
+    /// Spawn a background thread that watches every configured source for
+    /// writes and hot-reloads the merged fish list in place, so a
+    /// long-running bot session picks up an edited XP table (base or
+    /// overlay) without a restart. A reload that fails to parse is logged
+    /// and leaves the previously loaded data untouched.
+    pub fn watch(&self) -> notify::Result<()> {
+        use notify::{Event, EventKind, RecursiveMode, Watcher};
+        use std::sync::mpsc::channel;
+
+        let config_paths = self.config_paths.clone();
+        let data = Arc::clone(&self.data);
+
+        let (tx, rx) = channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        for path in &config_paths {
+            watcher.watch(path, RecursiveMode::NonRecursive)?;
+        }
+
+        thread::spawn(move || {
+            // Keep the watcher alive for as long as this thread runs.
+            let _watcher = watcher;
+            for res in rx {
+                let event = match res {
+                    Ok(event) => event,
+                    Err(e) => {
+                        tracing::warn!("[FISH] Watch error: {:?}", e);
+                        continue;
+                    }
+                };
+
+                if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                    continue;
+                }
+
+                match Self::merge_sources(&config_paths) {
+                    Ok((new_fishes, source_by_id)) => {
+                        let count = new_fishes.len();
+                        *data.write() = FishData::new(new_fishes, source_by_id);
+                        tracing::info!(
+                            "[FISH] Reloaded {} fish from {} source(s)",
+                            count,
+                            config_paths.len()
+                        );
+                    }
+                    Err(e) => tracing::warn!(
+                        "[FISH] Failed to reload fish sources, keeping previous data: {}",
+                        e
+                    ),
+                }
+            }
+        });
+
         Ok(())
     }
 
     /// Get all fish
-    pub fn get_all(&self) -> &[Fish] {
-        &self.fishes
+    pub fn get_all(&self) -> Vec<Fish> {
+        self.data.read().fishes.clone()
     }
 
     /// Get fish by rarity
-    pub fn get_by_rarity(&self, rarity: Rarity) -> Vec<&Fish> {
-        self.fishes.iter().filter(|f| f.rarity == rarity).collect()
+    pub fn get_by_rarity(&self, rarity: Rarity) -> Vec<Fish> {
+        let data = self.data.read();
+        data.by_rarity
+            .get(&rarity)
+            .map(|indexes| indexes.iter().map(|&i| data.fishes[i].clone()).collect())
+            .unwrap_or_default()
     }
 
     /// Get XP value for a given fish name or ID
     pub fn get_xp_by_type(&self, fish_type: &str) -> i32 {
         let fish_type_lower = fish_type.to_lowercase();
-        for fish in &self.fishes {
-            if fish.name.to_lowercase() == fish_type_lower || fish.id == fish_type {
-                return fish.xp;
-            }
-        }
-        0
+        let data = self.data.read();
+        data.by_name
+            .get(&fish_type_lower)
+            .or_else(|| data.by_id.get(&fish_type_lower))
+            .map(|&i| data.fishes[i].xp)
+            .unwrap_or(0)
     }
 
     /// Get fish by name
-    pub fn get_by_name(&self, name: &str) -> Option<&Fish> {
+    pub fn get_by_name(&self, name: &str) -> Option<Fish> {
         let name_lower = name.to_lowercase();
-        self.fishes
-            .iter()
-            .find(|f| f.name.to_lowercase() == name_lower)
+        let data = self.data.read();
+        data.by_name.get(&name_lower).map(|&i| data.fishes[i].clone())
     }
 
     /// Get fish by ID
-    pub fn get_by_id(&self, id: &str) -> Option<&Fish> {
+    pub fn get_by_id(&self, id: &str) -> Option<Fish> {
         let id_lower = id.to_lowercase();
-        self.fishes.iter().find(|f| f.id.to_lowercase() == id_lower)
+        let data = self.data.read();
+        data.by_id.get(&id_lower).map(|&i| data.fishes[i].clone())
     }
 
     /// Check if a fish exists by ID or name
     pub fn fish_exists(&self, fish_type: &str) -> bool {
         let fish_type_lower = fish_type.to_lowercase();
-        self.fishes.iter().any(|f| {
-            f.id.to_lowercase() == fish_type_lower || f.name.to_lowercase() == fish_type_lower
-        })
+        let data = self.data.read();
+        data.by_id.contains_key(&fish_type_lower) || data.by_name.contains_key(&fish_type_lower)
     }
 
     /// Get total number of fish in config
     pub fn count(&self) -> usize {
-        self.fishes.len()
+        self.data.read().fishes.len()
+    }
+
+    /// The keep/release policy currently configured in settings, read fresh
+    /// each call like `discord::webhook_url` - the dashboard can change it
+    /// mid-session without a restart and the next catch just sees it. Goes
+    /// through `get_settings`/`get_settings_for`, the same multi-format
+    /// (JSON/TOML/YAML/RON), profile-aware accessors the rest of the app
+    /// uses, rather than hand-parsing `settings.json` directly.
+    fn keep_policy() -> KeepPolicy {
+        let settings = match active_profile() {
+            Some(profile) => get_settings_for(&profile),
+            None => get_settings(),
+        };
+        settings
+            .get("fish_keep_policy")
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// Whether a caught fish (reported by name or id) passes the configured
+    /// keep policy. The fish is resolved first (same name-then-id lookup as
+    /// `get_xp_by_type`) so a `Whitelist`/`Blacklist` entry matches regardless
+    /// of whether it names the fish the same way `fish_type` does. A fish the
+    /// policy doesn't recognize by name/id is matched against `fish_type`
+    /// itself, and against `get_xp_by_type`'s default of `0`, so an unlisted
+    /// fish fails a `KeepAbove` threshold above zero the same way a
+    /// recognized 0-XP fish would.
+    pub fn should_keep(&self, fish_type: &str) -> bool {
+        let resolved = self.get_by_name(fish_type).or_else(|| self.get_by_id(fish_type));
+        let candidates: Vec<String> = match &resolved {
+            Some(fish) => vec![fish.name.to_lowercase(), fish.id.to_lowercase()],
+            None => vec![fish_type.to_lowercase()],
+        };
+
+        match Self::keep_policy() {
+            KeepPolicy::KeepAll => true,
+            KeepPolicy::KeepAbove(threshold) => self.get_xp_by_type(fish_type) >= threshold,
+            KeepPolicy::Whitelist(names) => {
+                let names: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+                candidates.iter().any(|c| names.contains(c))
+            }
+            KeepPolicy::Blacklist(names) => {
+                let names: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+                !candidates.iter().any(|c| names.contains(c))
+            }
+        }
     }
 }
 
@@ -94,4 +301,39 @@ mod tests {
         let service = FishService::new(PathBuf::from("nonexistent.json"));
         assert!(service.get_all().is_empty());
     }
+
+    #[test]
+    fn test_merge_sources_overlay_overwrites_by_id_and_appends_new() {
+        let dir = std::env::temp_dir().join("blue_mancing_fish_service_test");
+        fs::create_dir_all(&dir).unwrap();
+        let base_path = dir.join("base.json");
+        let overlay_path = dir.join("overlay.json");
+
+        fs::write(
+            &base_path,
+            r#"{"fishes":[
+                {"id":"a","image":"a.png","name":"Carp","xp":10,"rarity":"COMMON"},
+                {"id":"b","image":"b.png","name":"Trout","xp":20,"rarity":"COMMON"}
+            ]}"#,
+        )
+        .unwrap();
+        fs::write(
+            &overlay_path,
+            r#"{"fishes":[
+                {"id":"a","image":"a.png","name":"Carp","xp":999,"rarity":"MYTHICAL"},
+                {"id":"c","image":"c.png","name":"Custom Fish","xp":5,"rarity":"COMMON"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let (fishes, source_by_id) =
+            FishService::merge_sources(&[base_path.clone(), overlay_path.clone()]).unwrap();
+
+        assert_eq!(fishes.len(), 3);
+        assert_eq!(fishes[0].xp, 999);
+        assert_eq!(source_by_id.get("a"), Some(&overlay_path));
+        assert_eq!(source_by_id.get("b"), Some(&base_path));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -5,5 +5,5 @@
 pub mod base;
 pub mod fish_service;
 
-pub use base::{Fish, Rarity};
+pub use base::{Fish, KeepPolicy, Rarity};
 pub use fish_service::FishService;
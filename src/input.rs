@@ -1,30 +1,80 @@
 //! Input simulation module for mouse and keyboard control
+//!
+//! Backed by `enigo`, which drives Win32 `SendInput` on Windows, XTest/uinput
+//! on Linux (X11/Wayland), and CGEvent on macOS, so the same controllers and
+//! functions below work unmodified on every platform `enigo` supports.
 
-#[cfg(windows)]
 use enigo::{Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
-#[cfg(windows)]
 use parking_lot::Mutex;
-#[cfg(windows)]
 use once_cell::sync::Lazy;
-#[cfg(windows)]
+use rand::Rng;
 use std::thread;
-#[cfg(windows)]
 use std::time::Duration;
 
-#[cfg(windows)]
+/// The input-injection operations the fishing loop needs, kept as a trait
+/// rather than calling the free functions below directly so a caller that
+/// wants a typed, swappable layer (e.g. `OverviewApi::test_input`) doesn't
+/// have to know it's `enigo` underneath.
+pub trait InputBackend: Send + Sync {
+    /// Press and release a key, e.g. `"F"` or `"ESC"`.
+    fn key_tap(&self, key: &str);
+    /// Press and hold a key down.
+    fn key_down(&self, key: &str);
+    /// Release a previously held key.
+    fn key_up(&self, key: &str);
+    /// Move the mouse to an absolute screen position.
+    fn mouse_move(&self, x: i32, y: i32);
+    /// Move the mouse to an absolute screen position and left-click.
+    fn mouse_click(&self, x: i32, y: i32);
+}
+
+/// The default, and so far only, `InputBackend` - a thin wrapper over this
+/// module's `enigo`-backed free functions, which already are the
+/// cross-platform layer (`enigo` itself picks `SendInput`/XTest-uinput/
+/// CGEvent per OS). Zero-sized: all actual state lives in the `MOUSE`/
+/// `KEYBOARD` statics those functions share.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnigoInputBackend;
+
+impl EnigoInputBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InputBackend for EnigoInputBackend {
+    fn key_tap(&self, key: &str) {
+        press_key(key);
+    }
+
+    fn key_down(&self, key: &str) {
+        hold_key(key);
+    }
+
+    fn key_up(&self, key: &str) {
+        release_key(key);
+    }
+
+    fn mouse_move(&self, x: i32, y: i32) {
+        self::mouse_move(x, y);
+    }
+
+    fn mouse_click(&self, x: i32, y: i32) {
+        click(x, y);
+    }
+}
+
 /// Global mouse controller
 static MOUSE: Lazy<Mutex<Enigo>> = Lazy::new(|| {
     Mutex::new(Enigo::new(&Settings::default()).expect("Failed to create Enigo for mouse"))
 });
 
-#[cfg(windows)]
 /// Global keyboard controller
 static KEYBOARD: Lazy<Mutex<Enigo>> = Lazy::new(|| {
     Mutex::new(Enigo::new(&Settings::default()).expect("Failed to create Enigo for keyboard"))
 });
 
 /// Click at a position
-#[cfg(windows)]
 pub fn click(x: i32, y: i32) {
     tracing::debug!("[INPUT] click() - moving mouse to ({}, {}) and clicking", x, y);
     thread::sleep(Duration::from_millis(50));
@@ -41,18 +91,12 @@ pub fn click(x: i32, y: i32) {
     }
 }
 
-#[cfg(not(windows))]
-pub fn click(_x: i32, _y: i32) {
-    tracing::warn!("Click not implemented on this platform");
-}
-
 /// Press and release a key
-#[cfg(windows)]
 pub fn press_key(key: &str) {
     tracing::debug!("[INPUT] press_key('{}') - pressing and releasing key", key);
     thread::sleep(Duration::from_millis(50));
     let mut keyboard = KEYBOARD.lock();
-    
+
     if let Some(enigo_key) = string_to_enigo_key(key) {
         if let Err(e) = keyboard.key(enigo_key, Direction::Click) {
             tracing::warn!("[INPUT] Failed to press key '{}': {:?}", key, e);
@@ -64,17 +108,11 @@ pub fn press_key(key: &str) {
     }
 }
 
-#[cfg(not(windows))]
-pub fn press_key(_key: &str) {
-    tracing::warn!("press_key not implemented on this platform");
-}
-
 /// Hold a key down
-#[cfg(windows)]
 pub fn hold_key(key: &str) {
     tracing::trace!("[INPUT] hold_key('{}') - holding key down", key);
     let mut keyboard = KEYBOARD.lock();
-    
+
     if let Some(enigo_key) = string_to_enigo_key(key) {
         if let Err(e) = keyboard.key(enigo_key, Direction::Press) {
             tracing::warn!("[INPUT] Failed to hold key '{}': {:?}", key, e);
@@ -84,17 +122,11 @@ pub fn hold_key(key: &str) {
     }
 }
 
-#[cfg(not(windows))]
-pub fn hold_key(_key: &str) {
-    tracing::warn!("hold_key not implemented on this platform");
-}
-
 /// Release a held key
-#[cfg(windows)]
 pub fn release_key(key: &str) {
     tracing::trace!("[INPUT] release_key('{}') - releasing held key", key);
     let mut keyboard = KEYBOARD.lock();
-    
+
     if let Some(enigo_key) = string_to_enigo_key(key) {
         if let Err(e) = keyboard.key(enigo_key, Direction::Release) {
             tracing::warn!("[INPUT] Failed to release key '{}': {:?}", key, e);
@@ -104,13 +136,7 @@ pub fn release_key(key: &str) {
     }
 }
 
-#[cfg(not(windows))]
-pub fn release_key(_key: &str) {
-    tracing::warn!("release_key not implemented on this platform");
-}
-
 /// Press left mouse button down
-#[cfg(windows)]
 pub fn mouse_press() {
     tracing::debug!("[INPUT] mouse_press() - pressing left mouse button");
     let mut mouse = MOUSE.lock();
@@ -121,13 +147,7 @@ pub fn mouse_press() {
     }
 }
 
-#[cfg(not(windows))]
-pub fn mouse_press() {
-    tracing::warn!("mouse_press not implemented on this platform");
-}
-
 /// Release left mouse button
-#[cfg(windows)]
 pub fn mouse_release() {
     tracing::debug!("[INPUT] mouse_release() - releasing left mouse button");
     let mut mouse = MOUSE.lock();
@@ -138,13 +158,7 @@ pub fn mouse_release() {
     }
 }
 
-#[cfg(not(windows))]
-pub fn mouse_release() {
-    tracing::warn!("mouse_release not implemented on this platform");
-}
-
 /// Move mouse to position
-#[cfg(windows)]
 pub fn mouse_move(x: i32, y: i32) {
     tracing::debug!("[INPUT] mouse_move({}, {}) - moving mouse to position", x, y);
     let mut mouse = MOUSE.lock();
@@ -155,20 +169,272 @@ pub fn mouse_move(x: i32, y: i32) {
     }
 }
 
-#[cfg(not(windows))]
-pub fn mouse_move(_x: i32, _y: i32) {
-    tracing::warn!("mouse_move not implemented on this platform");
+/// Nudge the mouse one pixel and back, relative to its current position.
+///
+/// Used by `AntiAfkJitterModule` to keep the client from treating a long,
+/// otherwise input-idle session as AFK - small enough to never disturb lane
+/// tracking or button clicks.
+pub fn mouse_jitter() {
+    tracing::trace!("[INPUT] mouse_jitter() - nudging mouse to avoid AFK detection");
+    let mut mouse = MOUSE.lock();
+    if let Err(e) = mouse.move_mouse(1, 0, Coordinate::Rel) {
+        tracing::warn!("[INPUT] Failed to jitter mouse: {:?}", e);
+        return;
+    }
+    if let Err(e) = mouse.move_mouse(-1, 0, Coordinate::Rel) {
+        tracing::warn!("[INPUT] Failed to jitter mouse back: {:?}", e);
+    }
+}
+
+/// Is `token` one of the modifier keys recognized by the chord DSL below?
+fn is_modifier_token(token: &str) -> bool {
+    matches!(token.to_uppercase().as_str(), "SHIFT" | "CTRL" | "CONTROL" | "ALT")
+}
+
+/// Is `spec` (a bare key or `+`-joined chord) fully resolvable by
+/// `string_to_enigo_key`? Used to validate user-supplied keybind config
+/// entries before they're trusted at press time.
+pub(crate) fn is_valid_key_spec(spec: &str) -> bool {
+    let tokens: Vec<&str> = spec.split('+').map(str::trim).collect();
+    if tokens.iter().any(|t| t.is_empty()) {
+        return false;
+    }
+    let Some((&final_token, modifier_tokens)) = tokens.split_last() else {
+        return false;
+    };
+    string_to_enigo_key(final_token).is_some()
+        && modifier_tokens
+            .iter()
+            .all(|t| is_modifier_token(t) && string_to_enigo_key(t).is_some())
+}
+
+/// Press and release a `+`-joined chord (e.g. `"CTRL+ALT+DELETE"`) or a
+/// space-separated sequence of chords (e.g. `"TAB TAB ENTER"`).
+///
+/// Each step is split on `+`; all tokens but the last are held down as
+/// modifiers (in order) while the last is tapped, then modifiers are
+/// released in reverse order. A step made of a single token - modifier or
+/// not - is just tapped. An unknown token aborts that step (after releasing
+/// any modifiers already pressed) but does not stop the rest of the
+/// sequence.
+pub fn press_sequence(sequence: &str) {
+    for step in sequence.split_whitespace() {
+        press_chord(step);
+    }
+}
+
+fn press_chord(step: &str) {
+    let tokens: Vec<&str> = step.split('+').map(str::trim).filter(|s| !s.is_empty()).collect();
+    let Some((&final_token, modifier_tokens)) = tokens.split_last() else {
+        return;
+    };
+
+    if modifier_tokens.is_empty() {
+        tracing::trace!("[INPUT] press_sequence: tapping '{}'", final_token);
+        match string_to_enigo_key(final_token) {
+            Some(key) => {
+                if let Err(e) = KEYBOARD.lock().key(key, Direction::Click) {
+                    tracing::warn!("[INPUT] Failed to tap '{}': {:?}", final_token, e);
+                }
+            }
+            None => tracing::warn!("[INPUT] press_sequence: unknown key '{}'", final_token),
+        }
+        return;
+    }
+
+    let mut keyboard = KEYBOARD.lock();
+    let mut pressed = Vec::with_capacity(modifier_tokens.len());
+    for token in modifier_tokens {
+        if !is_modifier_token(token) {
+            tracing::warn!(
+                "[INPUT] press_sequence: '{}' is not a valid modifier, aborting step '{}'",
+                token,
+                step
+            );
+            release_pressed(&mut keyboard, &pressed);
+            return;
+        }
+        match string_to_enigo_key(token) {
+            Some(key) => {
+                if let Err(e) = keyboard.key(key, Direction::Press) {
+                    tracing::warn!("[INPUT] Failed to press modifier '{}': {:?}", token, e);
+                }
+                pressed.push(key);
+            }
+            None => {
+                tracing::warn!(
+                    "[INPUT] press_sequence: unknown modifier '{}', aborting step '{}'",
+                    token,
+                    step
+                );
+                release_pressed(&mut keyboard, &pressed);
+                return;
+            }
+        }
+    }
+
+    match string_to_enigo_key(final_token) {
+        Some(key) => {
+            if let Err(e) = keyboard.key(key, Direction::Click) {
+                tracing::warn!("[INPUT] Failed to tap '{}': {:?}", final_token, e);
+            }
+        }
+        None => tracing::warn!(
+            "[INPUT] press_sequence: unknown key '{}' in step '{}'",
+            final_token,
+            step
+        ),
+    }
+
+    release_pressed(&mut keyboard, &pressed);
+}
+
+/// Release previously pressed modifier keys in reverse order.
+fn release_pressed(keyboard: &mut Enigo, pressed: &[Key]) {
+    for key in pressed.iter().rev() {
+        if let Err(e) = keyboard.key(*key, Direction::Release) {
+            tracing::warn!("[INPUT] Failed to release modifier {:?}: {:?}", key, e);
+        }
+    }
+}
+
+/// Tuning knobs for `mouse_move_human`'s Bezier interpolation.
+#[derive(Debug, Clone, Copy)]
+pub struct HumanizeConfig {
+    /// Perpendicular control-point jitter, as a fraction of travel distance
+    /// (e.g. `0.05` = 5%).
+    pub jitter_fraction: f64,
+    /// Roughly one interpolation step per this many pixels of travel.
+    pub px_per_step: f64,
+    /// Upper bound on the number of interpolation steps.
+    pub max_steps: u32,
+    /// Base delay between steps; jittered by up to `step_jitter_ms` extra.
+    pub step_delay_ms: u64,
+    pub step_jitter_ms: u64,
+}
+
+impl Default for HumanizeConfig {
+    fn default() -> Self {
+        Self {
+            jitter_fraction: 0.05,
+            px_per_step: 10.0,
+            max_steps: 60,
+            step_delay_ms: 8,
+            step_jitter_ms: 6,
+        }
+    }
+}
+
+/// Move the mouse to `(x, y)` along a humanized cubic Bezier path (default
+/// tuning) instead of teleporting there in one `move_mouse(Abs)` call.
+pub fn mouse_move_human(x: i32, y: i32) {
+    mouse_move_human_with(x, y, HumanizeConfig::default());
+}
+
+/// Like [`mouse_move_human`], with explicit realism/speed tuning.
+///
+/// Reads the current cursor position as P0, treats `(x, y)` as P3, and
+/// derives control points P1/P2 by jittering the straight-line midpoint
+/// perpendicular to the direction of travel. The curve is sampled with a
+/// smoothstep-eased `t` so the cursor accelerates then decelerates, and the
+/// final sample is snapped exactly onto `(x, y)` regardless of rounding.
+pub fn mouse_move_human_with(x: i32, y: i32, config: HumanizeConfig) {
+    let mut mouse = MOUSE.lock();
+    let p0 = match mouse.location() {
+        Ok((cx, cy)) => (cx as f64, cy as f64),
+        Err(e) => {
+            tracing::warn!(
+                "[INPUT] mouse_move_human: failed to read cursor position, teleporting: {:?}",
+                e
+            );
+            (x as f64, y as f64)
+        }
+    };
+    let p3 = (x as f64, y as f64);
+
+    let dx = p3.0 - p0.0;
+    let dy = p3.1 - p0.1;
+    let distance = (dx * dx + dy * dy).sqrt();
+
+    let mut rng = rand::thread_rng();
+    let mid = ((p0.0 + p3.0) / 2.0, (p0.1 + p3.1) / 2.0);
+    let (perp_x, perp_y) = if distance > 0.0 {
+        (-dy / distance, dx / distance)
+    } else {
+        (0.0, 0.0)
+    };
+    let jitter_mag = distance * config.jitter_fraction;
+    let jitter = |rng: &mut rand::rngs::ThreadRng| {
+        if jitter_mag > 0.0 {
+            rng.gen_range(-jitter_mag..=jitter_mag)
+        } else {
+            0.0
+        }
+    };
+    let j1 = jitter(&mut rng);
+    let j2 = jitter(&mut rng);
+    let p1 = (mid.0 + perp_x * j1, mid.1 + perp_y * j1);
+    let p2 = (mid.0 + perp_x * j2, mid.1 + perp_y * j2);
+
+    let steps = ((distance / config.px_per_step).ceil() as u32).clamp(1, config.max_steps);
+
+    for step in 1..=steps {
+        let t = smoothstep(step as f64 / steps as f64);
+        let (px, py) = if step == steps {
+            (x, y)
+        } else {
+            let (bx, by) = bezier_point(p0, p1, p2, p3, t);
+            (bx.round() as i32, by.round() as i32)
+        };
+
+        if let Err(e) = mouse.move_mouse(px, py, Coordinate::Abs) {
+            tracing::warn!(
+                "[INPUT] mouse_move_human: failed to move to ({}, {}): {:?}",
+                px,
+                py,
+                e
+            );
+        }
+
+        if step < steps {
+            let jitter_ms = rng.gen_range(0..=config.step_jitter_ms);
+            thread::sleep(Duration::from_millis(config.step_delay_ms + jitter_ms));
+        }
+    }
+}
+
+/// Point at parameter `t` on the cubic Bezier curve through `p0..p3`.
+fn bezier_point(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    t: f64,
+) -> (f64, f64) {
+    let mt = 1.0 - t;
+    let a = mt * mt * mt;
+    let b = 3.0 * mt * mt * t;
+    let c = 3.0 * mt * t * t;
+    let d = t * t * t;
+    (
+        a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+        a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+    )
+}
+
+/// Ease `t` (`0.0..=1.0`) so speed ramps up then back down.
+fn smoothstep(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
 }
 
 /// Convert string key name to enigo Key
-#[cfg(windows)]
-fn string_to_enigo_key(key: &str) -> Option<Key> {
+pub(crate) fn string_to_enigo_key(key: &str) -> Option<Key> {
     // Handle single characters - use lowercase to avoid keyboard layout mapping issues
     if key.len() == 1 {
         let c = key.chars().next()?.to_ascii_lowercase();
         return Some(Key::Unicode(c));
     }
-    
+
     // Handle special keys
     let key_upper = key.to_uppercase();
     match key_upper.as_str() {
@@ -202,21 +468,107 @@ fn string_to_enigo_key(key: &str) -> Option<Key> {
         "CTRL" | "CONTROL" => Some(Key::Control),
         "ALT" => Some(Key::Alt),
         "CAPSLOCK" => Some(Key::CapsLock),
+        "F13" => Some(Key::F13),
+        "F14" => Some(Key::F14),
+        "F15" => Some(Key::F15),
+        "F16" => Some(Key::F16),
+        "F17" => Some(Key::F17),
+        "F18" => Some(Key::F18),
+        "F19" => Some(Key::F19),
+        "F20" => Some(Key::F20),
+        "F21" => Some(Key::F21),
+        "F22" => Some(Key::F22),
+        "F23" => Some(Key::F23),
+        "F24" => Some(Key::F24),
+        // Punctuation canonical names - the literal symbols (`-`, `[`, etc.)
+        // already resolve via the single-character branch above.
+        "GRAVE" | "BACKTICK" => Some(Key::Unicode('`')),
+        "MINUS" => Some(Key::Unicode('-')),
+        "EQUAL" | "EQUALS" => Some(Key::Unicode('=')),
+        "LBRACKET" => Some(Key::Unicode('[')),
+        "RBRACKET" => Some(Key::Unicode(']')),
+        "SEMICOLON" => Some(Key::Unicode(';')),
+        "QUOTE" | "APOSTROPHE" => Some(Key::Unicode('\'')),
+        "COMMA" => Some(Key::Unicode(',')),
+        "PERIOD" | "DOT" => Some(Key::Unicode('.')),
+        "SLASH" => Some(Key::Unicode('/')),
+        "BACKSLASH" => Some(Key::Unicode('\\')),
+        // Numpad digits type the same character as the number row.
+        "NUMPAD0" => Some(Key::Unicode('0')),
+        "NUMPAD1" => Some(Key::Unicode('1')),
+        "NUMPAD2" => Some(Key::Unicode('2')),
+        "NUMPAD3" => Some(Key::Unicode('3')),
+        "NUMPAD4" => Some(Key::Unicode('4')),
+        "NUMPAD5" => Some(Key::Unicode('5')),
+        "NUMPAD6" => Some(Key::Unicode('6')),
+        "NUMPAD7" => Some(Key::Unicode('7')),
+        "NUMPAD8" => Some(Key::Unicode('8')),
+        "NUMPAD9" => Some(Key::Unicode('9')),
+        "NUMPADADD" => Some(Key::Unicode('+')),
+        "NUMPADSUBTRACT" => Some(Key::Unicode('-')),
+        "NUMPADMULTIPLY" => Some(Key::Unicode('*')),
+        "NUMPADDIVIDE" => Some(Key::Unicode('/')),
+        "NUMPADDECIMAL" => Some(Key::Unicode('.')),
         _ => None,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    #[cfg(windows)]
     use super::*;
 
     #[test]
-    #[cfg(windows)]
     fn test_string_to_enigo_key() {
         assert!(string_to_enigo_key("A").is_some());
         assert!(string_to_enigo_key("F9").is_some());
         assert!(string_to_enigo_key("ESC").is_some());
         assert!(string_to_enigo_key("INVALID_KEY_NAME_THAT_DOES_NOT_EXIST").is_none());
     }
+
+    #[test]
+    fn test_string_to_enigo_key_punctuation_and_extended_keys() {
+        assert_eq!(string_to_enigo_key("-"), Some(Key::Unicode('-')));
+        assert_eq!(string_to_enigo_key("MINUS"), Some(Key::Unicode('-')));
+        assert_eq!(string_to_enigo_key("["), Some(Key::Unicode('[')));
+        assert_eq!(string_to_enigo_key("NUMPAD5"), Some(Key::Unicode('5')));
+        assert_eq!(string_to_enigo_key("NUMPADADD"), Some(Key::Unicode('+')));
+        assert_eq!(string_to_enigo_key("F13"), Some(Key::F13));
+        assert_eq!(string_to_enigo_key("F24"), Some(Key::F24));
+    }
+
+    #[test]
+    fn test_is_valid_key_spec() {
+        assert!(is_valid_key_spec("F9"));
+        assert!(is_valid_key_spec("CTRL+F9"));
+        assert!(is_valid_key_spec("alt + shift + m"));
+        assert!(!is_valid_key_spec("CTRL+"));
+        assert!(!is_valid_key_spec("NOT_A_REAL_KEY"));
+        assert!(!is_valid_key_spec(""));
+    }
+
+    #[test]
+    fn test_smoothstep_endpoints_and_midpoint() {
+        assert_eq!(smoothstep(0.0), 0.0);
+        assert_eq!(smoothstep(1.0), 1.0);
+        assert_eq!(smoothstep(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_bezier_point_endpoints() {
+        let p0 = (0.0, 0.0);
+        let p1 = (10.0, 20.0);
+        let p2 = (30.0, -20.0);
+        let p3 = (100.0, 50.0);
+        assert_eq!(bezier_point(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(bezier_point(p0, p1, p2, p3, 1.0), p3);
+    }
+
+    #[test]
+    fn test_is_modifier_token() {
+        assert!(is_modifier_token("ctrl"));
+        assert!(is_modifier_token("ALT"));
+        assert!(is_modifier_token("Shift"));
+        assert!(!is_modifier_token("F9"));
+        assert!(!is_modifier_token("DELETE"));
+    }
 }
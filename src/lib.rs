@@ -7,13 +7,22 @@
 pub mod fish;
 pub mod input;
 pub mod log_main;
+pub mod net;
+pub mod rules;
 pub mod screen_reader;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod ui;
 pub mod utils;
 pub mod window;
 
 // Re-exports for convenience
-pub use fish::{Fish, FishService, Rarity};
+pub use fish::{Fish, FishService, KeepPolicy, Rarity};
+pub use net::StatusServer;
+pub use rules::{evaluate_rules, Action as RuleAction, RuleEngine};
 pub use screen_reader::{get_resolution_folder, ImageService, ScreenService};
 pub use ui::{start_ui, OverviewApi, StatsApi, Window};
-pub use utils::{bot_state, keybinds, path::get_data_dir, spelling, updater};
+pub use utils::{
+    bot_state, config_format::ConfigFormat, fishing_keybinds, keybinds, path::get_data_dir,
+    spelling, updater,
+};
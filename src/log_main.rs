@@ -55,6 +55,9 @@ pub struct CatchLogEntry {
     pub status: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fish_type: Option<String>,
+    /// Caught, then released per the fish keep/release policy instead of kept.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub released: bool,
 }
 
 /// Log entry for broken rod
@@ -64,8 +67,16 @@ pub struct BrokenRodLogEntry {
     pub broken: bool,
 }
 
-/// Log a catch to the fishing log
-pub fn log_catch(status: bool, fish_type: Option<String>) {
+/// Log a catch to the fishing log. Returns the entry's timestamp so callers can
+/// cross-reference it against other artifacts recorded for the same event (e.g.
+/// `ImageService`'s debug-evidence PNGs).
+pub fn log_catch(status: bool, fish_type: Option<String>) -> String {
+    log_catch_with_release(status, fish_type, false)
+}
+
+/// `log_catch`, additionally tagging the entry as released when the catch
+/// failed the configured `fish::KeepPolicy` and was thrown back.
+pub fn log_catch_with_release(status: bool, fish_type: Option<String>, released: bool) -> String {
     let log_file = get_data_dir().join("logs").join("fishing_log.json");
 
     if let Some(parent) = log_file.parent() {
@@ -76,7 +87,9 @@ pub fn log_catch(status: bool, fish_type: Option<String>) {
         timestamp: Utc::now().to_rfc3339(),
         status,
         fish_type,
+        released,
     };
+    let timestamp = entry.timestamp.clone();
 
     let mut data: Vec<CatchLogEntry> = if log_file.exists() {
         fs::read_to_string(&log_file)
@@ -92,10 +105,14 @@ pub fn log_catch(status: bool, fish_type: Option<String>) {
     if let Ok(content) = serde_json::to_string_pretty(&data) {
         let _ = fs::write(&log_file, content);
     }
+
+    timestamp
 }
 
-/// Log a broken rod
-pub fn log_broken_rod() {
+/// Log a broken rod. Returns the entry's timestamp so callers can cross-reference
+/// it against other artifacts recorded for the same event (e.g. `ImageService`'s
+/// debug-evidence PNGs).
+pub fn log_broken_rod() -> String {
     let log_file = get_data_dir().join("logs").join("broken_rods.json");
 
     if let Some(parent) = log_file.parent() {
@@ -106,6 +123,7 @@ pub fn log_broken_rod() {
         timestamp: Utc::now().to_rfc3339(),
         broken: true,
     };
+    let timestamp = entry.timestamp.clone();
 
     let mut data: Vec<BrokenRodLogEntry> = if log_file.exists() {
         fs::read_to_string(&log_file)
@@ -121,6 +139,8 @@ pub fn log_broken_rod() {
     if let Ok(content) = serde_json::to_string_pretty(&data) {
         let _ = fs::write(&log_file, content);
     }
+
+    timestamp
 }
 
 #[cfg(test)]
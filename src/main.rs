@@ -11,32 +11,47 @@
 
 #![allow(unused_imports)]
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 
 use chrono::Utc;
-use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, GlobalHotKeyManager};
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager};
 use parking_lot::Mutex;
 
 mod fish;
 mod input;
 mod log_main;
+mod net;
+mod rules;
 mod screen_reader;
+#[cfg(feature = "tui")]
+mod tui;
 mod ui;
 mod utils;
 mod window;
 
 use fish::FishService;
-use input::{click, hold_key, mouse_move, mouse_press, mouse_release, press_key, release_key};
-use log_main::{load_sessions, log_broken_rod, log_catch, save_sessions, Session};
+use input::{click, hold_key, mouse_move, mouse_press, mouse_release, press_key, press_sequence, release_key};
+use log_main::{load_sessions, log_broken_rod, log_catch, log_catch_with_release, save_sessions, Session};
+use net::{RawControlServer, RemoteControlServer, StatusServer, TelemetryServer};
+use rules::{evaluate_rules as evaluate_rule_actions, Action as RuleAction};
+use screen_reader::base::Settings;
 use screen_reader::{get_resolution_folder, ImageService};
-use utils::bot_state::{BotActivity, SHARED_STATE};
-use utils::keybinds::{get_keys, get_pykey, string_to_code};
+use utils::audio_cue::{BiteDetectionMode, BiteListener};
+use utils::bot_modules::{LoopContext, ModuleRegistry};
+use utils::bot_state::{BotActivity, Message, SHARED_STATE};
+use utils::control::{self, ThreadControlEvent};
+use utils::events::{self, BotEvent};
+use utils::fishing_keybinds::{FishingAction, Keybinds};
+use utils::keybinds::{self, get_pykey};
 use utils::path::get_data_dir;
 use utils::spelling::fix_spelling;
-use utils::updater::{check_for_update_blocking, APP_VERSION};
+use utils::updater::{check_for_update_blocking, APP_VERSION, DEV_MODE};
+use ui::stats_api::FishStats;
 use window::{focus_blue_protocol_window, get_window_rect, select_window};
 
 // Constants
@@ -45,6 +60,8 @@ const CHECK_INTERVAL: Duration = Duration::from_millis(50);
 const THRESHOLD: f32 = 0.7;
 const SPAM_CPS: u32 = 20;
 const NO_PROGRESS_LIMIT: u64 = 45;
+const STATUS_SERVER_PORT: u16 = 9012;
+const TELEMETRY_SERVER_PORT: u16 = 9013;
 
 /// Session statistics
 #[derive(Debug, Clone, Default)]
@@ -55,6 +72,27 @@ struct SessionStats {
     rate: f64,
 }
 
+/// Accumulated timing for one profiled detector call, used by the opt-in
+/// template-match profiling mode.
+#[derive(Debug, Clone, Copy)]
+struct ProfileSample {
+    count: u64,
+    total: Duration,
+    min: Duration,
+    max: Duration,
+}
+
+impl Default for ProfileSample {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            total: Duration::ZERO,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+        }
+    }
+}
+
 /// Global state for the macro
 struct MacroState {
     running: AtomicBool,
@@ -62,6 +100,31 @@ struct MacroState {
     saved_continue_pos: Mutex<Option<(i32, i32)>>,
     last_progress_time: Mutex<Instant>,
     session_stats: Mutex<SessionStats>,
+    /// Drained once per loop iteration by `drain_control_events` so UI
+    /// changes below take effect immediately, no stop/start required.
+    control_rx: Receiver<ThreadControlEvent>,
+    /// Posted to by the hotkey thread instead of calling `handle_start_key`/
+    /// `handle_stop_key` across threads - see `utils::events`. `main_loop`
+    /// selects on this with a timeout for its usual image-polling cadence.
+    events_rx: Receiver<BotEvent>,
+    arrow_threshold: Mutex<f32>,
+    spam_cps: AtomicU32,
+    no_progress_limit: AtomicU64,
+    /// Opt-in template-match profiling, toggled by `ThreadControlEvent::ToggleProfiling`.
+    profiling_enabled: AtomicBool,
+    /// Per-operation call count and total/min/max duration, keyed by a
+    /// static label like `"find_minigame_arrow"`.
+    profile_samples: Mutex<HashMap<&'static str, ProfileSample>>,
+    /// Which automation modules are currently enabled, plus `AntiAfkJitter`'s
+    /// own tick. See `utils::bot_modules`.
+    modules: Mutex<ModuleRegistry>,
+    /// Toggled by the `TogglePause` hotkey/`ThreadControlEvent` - `main_loop`
+    /// skips casting/clicking while set, without tearing down the session.
+    paused: AtomicBool,
+    /// Set by the `ForceRecovery` hotkey/`ThreadControlEvent`; `main_loop`
+    /// consumes it via `take_force_recovery_request` and runs the same
+    /// recovery sequence the no-progress timeout triggers.
+    force_recovery_requested: AtomicBool,
 }
 
 impl MacroState {
@@ -72,9 +135,157 @@ impl MacroState {
             saved_continue_pos: Mutex::new(None),
             last_progress_time: Mutex::new(Instant::now()),
             session_stats: Mutex::new(SessionStats::default()),
+            control_rx: control::take_receiver(),
+            events_rx: events::take_receiver(),
+            arrow_threshold: Mutex::new(THRESHOLD),
+            spam_cps: AtomicU32::new(SPAM_CPS),
+            no_progress_limit: AtomicU64::new(NO_PROGRESS_LIMIT),
+            profiling_enabled: AtomicBool::new(false),
+            profile_samples: Mutex::new(HashMap::new()),
+            modules: Mutex::new(ModuleRegistry::new()),
+            paused: AtomicBool::new(false),
+            force_recovery_requested: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether the named automation module (see `utils::bot_modules`) is
+    /// currently enabled.
+    fn module_enabled(&self, name: &str) -> bool {
+        self.modules.lock().is_enabled(name)
+    }
+
+    /// Drain any pending control events, applying each to the live fields
+    /// above. Call once per macro-loop iteration.
+    fn drain_control_events(&self) {
+        while let Ok(event) = self.control_rx.try_recv() {
+            match event {
+                ThreadControlEvent::UpdateArrowThreshold(v) => {
+                    tracing::info!("[CONTROL] Arrow threshold updated to {}", v);
+                    *self.arrow_threshold.lock() = v;
+                }
+                ThreadControlEvent::UpdateSpamCps(v) => {
+                    tracing::info!("[CONTROL] Spam CPS updated to {}", v);
+                    self.spam_cps.store(v, Ordering::SeqCst);
+                }
+                ThreadControlEvent::UpdateNoProgressLimit(v) => {
+                    tracing::info!("[CONTROL] No-progress limit updated to {}s", v);
+                    self.no_progress_limit.store(v, Ordering::SeqCst);
+                }
+                ThreadControlEvent::ResetStats => {
+                    tracing::info!("[CONTROL] Stats reset by request");
+                    *self.session_stats.lock() = SessionStats::default();
+                    SHARED_STATE.reset_stats();
+                }
+                ThreadControlEvent::RebindKeys => {
+                    // Keybinds are already read fresh via get_pykey() on every
+                    // use, so there's nothing cached to invalidate here yet -
+                    // this exists so a future keybind cache has a hook to
+                    // refresh from.
+                    tracing::info!("[CONTROL] Rebind-keys event received");
+                }
+                ThreadControlEvent::ToggleProfiling(enabled) => {
+                    tracing::info!("[CONTROL] Detector profiling {}", if enabled { "enabled" } else { "disabled" });
+                    self.profiling_enabled.store(enabled, Ordering::SeqCst);
+                    self.profile_samples.lock().clear();
+                }
+                ThreadControlEvent::SetModuleEnabled(name, enabled) => {
+                    tracing::info!(
+                        "[CONTROL] Module '{}' {}",
+                        name,
+                        if enabled { "enabled" } else { "disabled" }
+                    );
+                    self.modules.lock().set_enabled(&name, enabled);
+                }
+                ThreadControlEvent::TogglePause => {
+                    let now_paused = !self.paused.load(Ordering::SeqCst);
+                    self.paused.store(now_paused, Ordering::SeqCst);
+                    tracing::info!("[CONTROL] Bot {}", if now_paused { "paused" } else { "resumed" });
+                    if now_paused {
+                        SHARED_STATE.set_detail_message("Paused");
+                    }
+                }
+                ThreadControlEvent::RequestForceRecovery => {
+                    tracing::info!("[CONTROL] Force-recovery requested");
+                    self.force_recovery_requested.store(true, Ordering::SeqCst);
+                }
+            }
         }
     }
 
+    /// Whether `main_loop` should hold off on casting/clicking because the
+    /// user toggled pause.
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Consume a pending force-recovery request, if any. Returns `true` at
+    /// most once per `RequestForceRecovery` event.
+    fn take_force_recovery_request(&self) -> bool {
+        self.force_recovery_requested.swap(false, Ordering::SeqCst)
+    }
+
+    /// Run `f`, and if profiling is enabled, record its elapsed time under
+    /// `name` in `profile_samples`. When profiling is off it just calls `f`,
+    /// with no timer and no locking, so normal runs pay no profiling cost.
+    fn time_op<T>(&self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        if !self.profiling_enabled.load(Ordering::Relaxed) {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        let mut samples = self.profile_samples.lock();
+        let sample = samples.entry(name).or_default();
+        sample.count += 1;
+        sample.total += elapsed;
+        sample.min = sample.min.min(elapsed);
+        sample.max = sample.max.max(elapsed);
+
+        result
+    }
+
+    /// Print a table of accumulated detector timings, if profiling is on.
+    fn log_profile_table(&self) {
+        if !self.profiling_enabled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let samples = self.profile_samples.lock();
+        if samples.is_empty() {
+            return;
+        }
+
+        println!("---- Detector timing (count / avg / min / max) ----");
+        tracing::info!("[PROFILE] ---- Detector timing (count / avg / min / max) ----");
+        for (name, sample) in samples.iter() {
+            let avg_ms = (sample.total.as_secs_f64() * 1000.0) / sample.count.max(1) as f64;
+            let line = format!(
+                "{:<28} {:>5} calls  avg={:>7.2}ms  min={:>7.2}ms  max={:>7.2}ms",
+                name,
+                sample.count,
+                avg_ms,
+                sample.min.as_secs_f64() * 1000.0,
+                sample.max.as_secs_f64() * 1000.0,
+            );
+            println!("{}", line);
+            tracing::info!("[PROFILE] {}", line);
+        }
+    }
+
+    fn arrow_threshold(&self) -> f32 {
+        *self.arrow_threshold.lock()
+    }
+
+    fn spam_cps(&self) -> u32 {
+        self.spam_cps.load(Ordering::SeqCst)
+    }
+
+    fn no_progress_limit(&self) -> u64 {
+        self.no_progress_limit.load(Ordering::SeqCst)
+    }
+
     /// Check if bot is running - uses SHARED_STATE as the single source of truth
     fn is_running(&self) -> bool {
         SHARED_STATE.is_running()
@@ -109,6 +320,7 @@ fn handle_start_key(state: &Arc<MacroState>) {
         println!("No window found. Cannot start macro.");
         SHARED_STATE.set_activity(BotActivity::Idle);
         SHARED_STATE.set_detail_message("No game window found");
+        SHARED_STATE.push_message(Message::warn("Couldn't find the Blue Protocol window - is the game running?"));
         return;
     }
 
@@ -187,7 +399,41 @@ fn handle_stop_key(state: &Arc<MacroState>) {
     println!("Macro stopped");
 }
 
-/// Post-catch loop - handles the fishing minigame
+/// Reload `rules.json` and evaluate every rule against the latest
+/// `FishStats` window, applying each fired action. Called right after a
+/// catch/miss/broken-rod is logged, so rules react to the stats update that
+/// event just caused (e.g. `catch_rate<40:stop` or `broken_rods>3:...`).
+fn apply_rule_actions(state: &Arc<MacroState>) {
+    let stats = FishStats::new();
+    for action in evaluate_rule_actions(&stats) {
+        match action {
+            RuleAction::Stop => {
+                tracing::info!("[RULES] Rule fired: stop");
+                handle_stop_key(state);
+            }
+            RuleAction::Start => {
+                tracing::info!("[RULES] Rule fired: start");
+                handle_start_key(state);
+            }
+            RuleAction::PressKey(config_key) => {
+                if let Some(key) = get_pykey(&config_key) {
+                    tracing::info!("[RULES] Rule fired: press_key:{} ('{}')", config_key, key);
+                    press_key(&key);
+                } else {
+                    tracing::warn!("[RULES] Rule fired press_key:{} but no such key is configured", config_key);
+                }
+            }
+        }
+    }
+}
+
+/// Post-catch loop - handles the fishing minigame.
+///
+/// Alongside the mouse/image-driven mechanic, this also taps the logical
+/// `Reel`/`Confirm` keys from `Keybinds::load()` at the matching points
+/// (minigame start, continue-button click), so a user who's remapped
+/// `keybinds.ron` gets those inputs sent too rather than the config being
+/// read and silently ignored.
 fn post_catch_loop(
     state: &Arc<MacroState>,
     image_service: &ImageService,
@@ -205,7 +451,8 @@ fn post_catch_loop(
     let mut last_check_time = Instant::now();
     let mut lane = 0i32;
 
-    tracing::debug!("[MINIGAME] Pressing and holding left mouse button...");
+    tracing::debug!("[MINIGAME] Tapping reel key and holding left mouse button...");
+    press_sequence(Keybinds::load().get(FishingAction::Reel));
     mouse_press();
 
     SHARED_STATE.set_activity(BotActivity::PlayingMinigame);
@@ -213,14 +460,18 @@ fn post_catch_loop(
     tracing::info!("[MINIGAME] Minigame started - holding click, initial lane=0");
 
     while state.is_running() {
+        state.drain_control_events();
+
         // Check for no progress timeout
-        if state.time_since_progress().as_secs() > NO_PROGRESS_LIMIT {
+        if state.module_enabled("NoProgressRecovery")
+            && state.time_since_progress().as_secs() > state.no_progress_limit()
+        {
             handle_no_progress_loop(state, image_service, window_title);
             return;
         }
 
         counter += 1;
-        thread::sleep(Duration::from_millis(1000 / SPAM_CPS as u64));
+        thread::sleep(Duration::from_millis(1000 / state.spam_cps() as u64));
 
         let rect = match get_window_rect(window_title) {
             Some(r) => r,
@@ -228,7 +479,8 @@ fn post_catch_loop(
         };
 
         // Check for arrows in minigame
-        let (arrow, score) = image_service.find_minigame_arrow(Some(rect), None);
+        let (arrow, score) =
+            state.time_op("find_minigame_arrow", || image_service.find_minigame_arrow(Some(rect), None));
 
         if let Some(ref arrow_name) = arrow {
             if score > 0.8 {
@@ -270,37 +522,40 @@ fn post_catch_loop(
             }
         }
 
-        // Handle lane movement
-        match lane {
-            -1 => {
-                tracing::trace!("[MINIGAME] Lane=-1: holding LEFT key, releasing RIGHT key");
-                if let Some(key) = get_pykey("left_key") {
-                    hold_key(&key);
-                }
-                if let Some(key) = get_pykey("right_key") {
-                    release_key(&key);
-                }
-            }
-            0 => {
-                tracing::trace!("[MINIGAME] Lane=0: releasing both LEFT and RIGHT keys");
-                SHARED_STATE.set_activity(BotActivity::CenterLane);
-                if let Some(key) = get_pykey("left_key") {
-                    release_key(&key);
-                }
-                if let Some(key) = get_pykey("right_key") {
-                    release_key(&key);
+        // Handle lane movement - skipped when MinigameLaneSolver is disabled,
+        // so the bot just holds click without actively following the arrows.
+        if state.module_enabled("MinigameLaneSolver") {
+            match lane {
+                -1 => {
+                    tracing::trace!("[MINIGAME] Lane=-1: holding LEFT key, releasing RIGHT key");
+                    if let Some(key) = get_pykey("left_key") {
+                        hold_key(&key);
+                    }
+                    if let Some(key) = get_pykey("right_key") {
+                        release_key(&key);
+                    }
                 }
-            }
-            1 => {
-                tracing::trace!("[MINIGAME] Lane=1: holding RIGHT key, releasing LEFT key");
-                if let Some(key) = get_pykey("right_key") {
-                    hold_key(&key);
+                0 => {
+                    tracing::trace!("[MINIGAME] Lane=0: releasing both LEFT and RIGHT keys");
+                    SHARED_STATE.set_activity(BotActivity::CenterLane);
+                    if let Some(key) = get_pykey("left_key") {
+                        release_key(&key);
+                    }
+                    if let Some(key) = get_pykey("right_key") {
+                        release_key(&key);
+                    }
                 }
-                if let Some(key) = get_pykey("left_key") {
-                    release_key(&key);
+                1 => {
+                    tracing::trace!("[MINIGAME] Lane=1: holding RIGHT key, releasing LEFT key");
+                    if let Some(key) = get_pykey("right_key") {
+                        hold_key(&key);
+                    }
+                    if let Some(key) = get_pykey("left_key") {
+                        release_key(&key);
+                    }
                 }
+                _ => {}
             }
-            _ => {}
         }
 
         // Print tick count periodically
@@ -314,6 +569,7 @@ fn post_catch_loop(
             println!("Held for {} ticks", counter);
             SHARED_STATE
                 .set_detail_message(format!("Minigame: {} ticks, lane = {}", counter, lane));
+            state.log_profile_table();
             last_print_time = Instant::now();
         }
 
@@ -333,11 +589,13 @@ fn post_catch_loop(
                 .join(&res_folder)
                 .join("continue_highlighted.png");
 
-            let mut continue_found =
-                image_service.find_image_in_window(Some(rect), &continue_path, 0.8);
+            let mut continue_found = state.time_op("find_image_in_window:continue", || {
+                image_service.find_image_in_window(Some(rect), &continue_path, 0.8)
+            });
             if continue_found.is_none() {
-                continue_found =
-                    image_service.find_image_in_window(Some(rect), &continue_hl_path, 0.8);
+                continue_found = state.time_op("find_image_in_window:continue_highlighted", || {
+                    image_service.find_image_in_window(Some(rect), &continue_hl_path, 0.8)
+                });
             }
 
             // Check for default screen (minigame failed)
@@ -345,7 +603,9 @@ fn post_catch_loop(
                 .join(TARGET_IMAGES_FOLDER)
                 .join(&res_folder)
                 .join("default_screen.png");
-            let default_found = image_service.find_image_in_window(Some(rect), &default_path, 0.9);
+            let default_found = state.time_op("find_image_in_window:default_screen", || {
+                image_service.find_image_in_window(Some(rect), &default_path, 0.9)
+            });
 
             last_check_time = Instant::now();
 
@@ -391,7 +651,7 @@ fn post_catch_loop(
                     .join("fish");
                 let mut fish_type: Option<String> = None;
 
-                if fish_folder.exists() {
+                if state.module_enabled("FishTypeDetection") && fish_folder.exists() {
                     tracing::debug!("[FISH] Fish folder exists: {:?}", fish_folder);
                     for attempt in 0..3 {
                         tracing::debug!("[FISH] Detection attempt {}/3...", attempt + 1);
@@ -400,8 +660,9 @@ fn post_catch_loop(
                             "Detecting fish (attempt {}/3)...",
                             attempt + 1
                         ));
-                        let (detected, score) =
-                            image_service.find_best_matching_fish(Some(rect), None);
+                        let (detected, score) = state.time_op("find_best_matching_fish", || {
+                            image_service.find_best_matching_fish(Some(rect), None)
+                        });
                         if let Some(ref ft) = detected {
                             tracing::info!("[FISH] Found match: '{}' with score={:.3}", ft, score);
                             println!("[FISH] Found match: '{}' with score={:.3}", ft, score);
@@ -448,11 +709,39 @@ fn post_catch_loop(
                         tracing::warn!("[FISH] Failed to detect fish type after 3 attempts");
                         println!("[FISH] ✗ Failed to detect fish type after 3 attempts");
                     }
+                } else if !state.module_enabled("FishTypeDetection") {
+                    tracing::debug!("[FISH] FishTypeDetection module disabled, skipping");
                 } else {
                     tracing::warn!("[FISH] Fish folder does not exist: {:?}", fish_folder);
                     println!("[FISH] ✗ Fish folder does not exist: {:?}", fish_folder);
                 }
 
+                // Check the caught fish against the configured keep/release
+                // policy (see `fish::KeepPolicy`). A fish we couldn't
+                // identify is always kept - there's nothing to judge it by.
+                let keep = fish_type
+                    .as_deref()
+                    .map(|ft| fish_service.should_keep(ft))
+                    .unwrap_or(true);
+
+                if !keep {
+                    tracing::info!(
+                        "[FISH] '{}' failed the keep policy - releasing",
+                        fish_type.as_deref().unwrap_or("unknown")
+                    );
+                    println!(
+                        "[FISH] Releasing '{}' per keep policy",
+                        fish_type.as_deref().unwrap_or("unknown")
+                    );
+                    SHARED_STATE.set_detail_message(format!(
+                        "Releasing {} (keep policy)",
+                        fish_type.as_deref().unwrap_or("fish")
+                    ));
+                    if let Some(key) = get_pykey("discard_key") {
+                        press_key(&key);
+                    }
+                }
+
                 // Update stats
                 {
                     let mut stats = state.session_stats.lock();
@@ -483,53 +772,79 @@ fn post_catch_loop(
 
                     // Sync to shared state for UI
                     SHARED_STATE.update_stats(stats.catches, stats.misses, stats.xp);
+                    if !keep {
+                        SHARED_STATE.increment_released();
+                    }
+                    let caught_fish = fish_type
+                        .as_deref()
+                        .and_then(|ft| fish_service.get_by_name(ft).or_else(|| fish_service.get_by_id(ft)));
+                    // record_catch_breakdown already raises the rare-catch
+                    // notification (and the catch-logged event) for `fish` -
+                    // don't also raise it here, or a rare catch gets two
+                    // identical desktop toasts.
+                    SHARED_STATE.record_catch_breakdown(caught_fish.as_ref());
                 }
 
                 // Log the catch with fish type
-                tracing::info!("[LOG] Logging catch to file: fish_type={:?}", fish_type);
+                tracing::info!(
+                    "[LOG] Logging catch to file: fish_type={:?}, released={}",
+                    fish_type,
+                    !keep
+                );
                 println!("[LOG] Logging catch to file: fish_type={:?}", fish_type);
-                log_catch(true, fish_type);
-
-                // Click continue button with retries
-                tracing::info!("[CLICK] Starting continue button click sequence...");
-                SHARED_STATE.set_activity(BotActivity::ClickingContinue);
-                SHARED_STATE.set_detail_message("Clicking continue button...");
-
-                for retry in 0..3 {
-                    tracing::debug!("[CLICK] Attempt {}/3: focusing window...", retry + 1);
-                    // Focus window before clicking to ensure click goes to the right place
-                    focus_blue_protocol_window();
-
-                    if let Some(continue_pos) = *state.saved_continue_pos.lock() {
-                        tracing::info!(
-                            "[CLICK] Clicking continue button at ({}, {}) - attempt {}/3",
-                            continue_pos.0,
-                            continue_pos.1,
-                            retry + 1
-                        );
-                        click(continue_pos.0, continue_pos.1);
-                        SHARED_STATE.set_detail_message(format!("Click attempt {}/3", retry + 1));
-                        thread::sleep(Duration::from_millis(500));
-                    } else {
-                        tracing::warn!("[CLICK] No saved continue position available!");
-                    }
-
-                    // Check if continue button is still there
-                    tracing::debug!("[CLICK] Checking if continue button is still visible...");
-                    let still_there = image_service
-                        .find_image_in_window(Some(rect), &continue_path, 0.75)
-                        .or_else(|| {
-                            image_service.find_image_in_window(Some(rect), &continue_hl_path, 0.75)
-                        });
+                let catch_timestamp = log_catch_with_release(true, fish_type, !keep);
+                image_service.link_evidence_to_log(&catch_timestamp);
+                apply_rule_actions(state);
+
+                // Click continue button with retries - skipped when AutoRecast is
+                // disabled, leaving the continue button for the user to click.
+                if state.module_enabled("AutoRecast") {
+                    tracing::info!("[CLICK] Starting continue button click sequence...");
+                    SHARED_STATE.set_activity(BotActivity::ClickingContinue);
+                    SHARED_STATE.set_detail_message("Clicking continue button...");
+
+                    let keybinds = Keybinds::load();
+                    for retry in 0..3 {
+                        tracing::debug!("[CLICK] Attempt {}/3: focusing window...", retry + 1);
+                        // Focus window before clicking to ensure click goes to the right place
+                        focus_blue_protocol_window();
+                        press_sequence(keybinds.get(FishingAction::FocusWindow));
+
+                        if let Some(continue_pos) = *state.saved_continue_pos.lock() {
+                            tracing::info!(
+                                "[CLICK] Clicking continue button at ({}, {}) - attempt {}/3",
+                                continue_pos.0,
+                                continue_pos.1,
+                                retry + 1
+                            );
+                            click(continue_pos.0, continue_pos.1);
+                            press_sequence(keybinds.get(FishingAction::Confirm));
+                            SHARED_STATE.set_detail_message(format!("Click attempt {}/3", retry + 1));
+                            thread::sleep(Duration::from_millis(500));
+                        } else {
+                            tracing::warn!("[CLICK] No saved continue position available!");
+                        }
 
-                    if still_there.is_none() {
-                        tracing::info!(
-                            "[CLICK] Continue button no longer visible - click successful!"
-                        );
-                        break;
-                    } else {
-                        tracing::debug!("[CLICK] Continue button still visible, retrying...");
+                        // Check if continue button is still there
+                        tracing::debug!("[CLICK] Checking if continue button is still visible...");
+                        let still_there = image_service
+                            .find_image_in_window(Some(rect), &continue_path, 0.75)
+                            .or_else(|| {
+                                image_service
+                                    .find_image_in_window(Some(rect), &continue_hl_path, 0.75)
+                            });
+
+                        if still_there.is_none() {
+                            tracing::info!(
+                                "[CLICK] Continue button no longer visible - click successful!"
+                            );
+                            break;
+                        } else {
+                            tracing::debug!("[CLICK] Continue button still visible, retrying...");
+                        }
                     }
+                } else {
+                    tracing::debug!("[CLICK] AutoRecast module disabled, leaving continue button for the user");
                 }
 
                 // Release any held movement keys before returning
@@ -583,7 +898,9 @@ fn post_catch_loop(
                     SHARED_STATE.update_stats(stats.catches, stats.misses, stats.xp);
                 }
 
-                log_catch(false, None);
+                let catch_timestamp = log_catch(false, None);
+                image_service.link_evidence_to_log(&catch_timestamp);
+                apply_rule_actions(state);
 
                 thread::sleep(Duration::from_millis(500));
                 SHARED_STATE.set_activity(BotActivity::WaitingForDefaultScreen);
@@ -603,11 +920,24 @@ fn handle_no_progress_loop(
     tracing::warn!("[RECOVERY] ========== NO PROGRESS TIMEOUT - STARTING RECOVERY ==========");
     tracing::warn!(
         "[RECOVERY] No progress for {} seconds, initiating recovery sequence",
-        NO_PROGRESS_LIMIT
+        state.no_progress_limit()
     );
     println!("No progress detected, performing recovery...");
     SHARED_STATE.set_activity(BotActivity::RecoveringFromTimeout);
-    SHARED_STATE.set_detail_message("No progress for 45s, recovering...");
+    SHARED_STATE.set_detail_message(format!(
+        "No progress for {}s, recovering...",
+        state.no_progress_limit()
+    ));
+    utils::notifications::maybe_notify_recovery_started();
+
+    image_service.dump_frame_ring(
+        "no_progress_timeout",
+        &serde_json::json!({
+            "arrow_threshold": state.arrow_threshold(),
+            "spam_cps": state.spam_cps(),
+            "no_progress_limit": state.no_progress_limit(),
+        }),
+    );
 
     // Release mouse button and any held movement keys before recovery
     tracing::debug!("[RECOVERY] Releasing mouse button and movement keys...");
@@ -642,6 +972,17 @@ fn handle_no_progress_loop(
                 state.set_running(false);
                 SHARED_STATE.set_activity(BotActivity::Stopped);
                 SHARED_STATE.set_detail_message("Game window lost");
+                SHARED_STATE.push_message(Message::err("Game window lost - bot stopped"));
+                utils::notifications::maybe_notify_stop_on_error("Game window lost");
+                events::send(BotEvent::WindowLost);
+                image_service.dump_frame_ring(
+                    "window_lost",
+                    &serde_json::json!({
+                        "arrow_threshold": state.arrow_threshold(),
+                        "spam_cps": state.spam_cps(),
+                        "no_progress_limit": state.no_progress_limit(),
+                    }),
+                );
                 thread::sleep(Duration::from_secs(1));
                 continue;
             }
@@ -663,6 +1004,7 @@ fn handle_no_progress_loop(
             tracing::info!("[RECOVERY] Default screen detected - recovery successful!");
             println!("Default screen detected, stopping recovery loop.");
             SHARED_STATE.set_detail_message("Recovery successful, restarting...");
+            utils::notifications::maybe_notify_recovery_succeeded();
             state.update_progress();
 
             // Restart macro
@@ -696,38 +1038,102 @@ fn handle_no_progress_loop(
     tracing::info!("[RECOVERY] ========== RECOVERY LOOP ENDED ==========");
 }
 
-/// Main fishing loop
+/// Main fishing loop.
+///
+/// Taps the logical `Cast` key from `Keybinds::load()` alongside the casting
+/// mouse click, so `keybinds.ron` actually drives input rather than only
+/// being parsed.
 fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service: FishService) {
     tracing::info!("[MAIN] ========== MAIN FISHING LOOP STARTED ==========");
-    tracing::info!("[MAIN] Waiting for START key: {:?}", get_keys().0);
-    println!("Macro waiting for START key ({:?})", get_keys().0);
+    tracing::info!("[MAIN] Waiting for START key: {:?}", keybinds::get_binding(keybinds::Action::Start));
+    println!("Macro waiting for START key ({:?})", keybinds::get_binding(keybinds::Action::Start));
     SHARED_STATE.set_activity(BotActivity::WaitingForStart);
-    SHARED_STATE.set_detail_message(format!("Press {} to start", get_keys().0));
+    SHARED_STATE.set_detail_message(format!("Press {} to start", keybinds::get_binding(keybinds::Action::Start)));
+
+    // Start listening for the bite sound up front, alongside the image
+    // detector, if the settings ask for it - one listener for the whole
+    // run, since `BiteListener::take_detected` just consumes whatever's
+    // been heard since the last check.
+    let bite_settings = Settings::load();
+    let bite_mode = BiteDetectionMode::from_setting(&bite_settings.bite_detection_mode);
+    let bite_listener: Option<BiteListener> = if bite_mode.uses_audio() {
+        let clip_path = get_data_dir().join(&bite_settings.bite_audio_reference_clip);
+        let threshold: f32 = bite_settings.bite_audio_threshold.parse().unwrap_or(0.35);
+        match BiteListener::start(&clip_path, threshold) {
+            Ok(listener) => {
+                tracing::info!("[MAIN] Audio bite listener started ({})", clip_path.display());
+                Some(listener)
+            }
+            Err(e) => {
+                tracing::warn!("[MAIN] Failed to start audio bite listener: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
 
+    const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
     let mut loop_counter: u64 = 0;
     loop {
         loop_counter += 1;
+        state.drain_control_events();
+
+        // Select on the event channel for up to the old poll interval - a
+        // posted `BotEvent` wakes this immediately instead of waiting out a
+        // blind sleep, and a timeout falls through to the loop body below
+        // at the same cadence as before.
+        match state.events_rx.recv_timeout(EVENT_POLL_INTERVAL) {
+            Ok(BotEvent::StartRequested) => {
+                tracing::info!("[MAIN] StartRequested event received");
+                handle_start_key(&state);
+            }
+            Ok(BotEvent::StopRequested) => {
+                tracing::info!("[MAIN] StopRequested event received");
+                handle_stop_key(&state);
+            }
+            Ok(BotEvent::ConfigUpdated) => {
+                tracing::info!("[MAIN] ConfigUpdated event received");
+            }
+            Ok(BotEvent::WindowLost) => {
+                tracing::warn!("[MAIN] WindowLost event received");
+            }
+            Ok(BotEvent::ProgressTick) => {
+                state.update_progress();
+            }
+            Ok(BotEvent::Shutdown) => {
+                tracing::info!("[MAIN] Shutdown event received, exiting main loop");
+                break;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                tracing::error!("[MAIN] Event channel disconnected, exiting main loop");
+                break;
+            }
+        }
 
-        // Sync local state with shared state (UI can start/stop the bot)
+        // Fallback reconciliation for the UI/telemetry/remote-control
+        // surfaces that still flip `SHARED_STATE`'s running flag directly
+        // instead of posting a `BotEvent` - see `utils::events` module docs.
         let shared_running = SHARED_STATE.is_running();
         let local_running = state.running.load(Ordering::SeqCst);
 
-        // Check if UI started the bot (shared is running but local isn't initialized)
         if shared_running && !local_running && state.window_title.lock().is_none() {
-            tracing::info!("[MAIN] UI triggered start - initializing...");
-            // UI started the bot, trigger start sequence
+            tracing::debug!("[MAIN] UI triggered start (legacy path) - initializing...");
             handle_start_key(&state);
         }
 
-        // Check if UI stopped the bot
         if !shared_running && local_running {
-            tracing::info!("[MAIN] UI triggered stop - shutting down...");
-            // UI stopped the bot, trigger stop sequence
+            tracing::debug!("[MAIN] UI triggered stop (legacy path) - shutting down...");
             handle_stop_key(&state);
         }
 
         if !state.is_running() {
-            thread::sleep(Duration::from_millis(100));
+            continue;
+        }
+
+        if state.is_paused() {
+            SHARED_STATE.set_detail_message("Paused");
             continue;
         }
 
@@ -743,13 +1149,19 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
             }
         };
 
+        if state.take_force_recovery_request() {
+            tracing::warn!("[MAIN] Force-recovery requested via hotkey");
+            handle_no_progress_loop(&state, &image_service, &window_title);
+            continue;
+        }
+
         // Check for no progress timeout
         let time_since_progress = state.time_since_progress().as_secs();
-        if time_since_progress > NO_PROGRESS_LIMIT {
+        if state.module_enabled("NoProgressRecovery") && time_since_progress > state.no_progress_limit() {
             tracing::warn!(
                 "[MAIN] No progress timeout! time_since_progress={}s > limit={}s",
                 time_since_progress,
-                NO_PROGRESS_LIMIT
+                state.no_progress_limit()
             );
             handle_no_progress_loop(&state, &image_service, &window_title);
             continue;
@@ -778,7 +1190,7 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
             .join("default_screen.png");
 
         if image_service
-            .find_image_in_window(Some(rect), &default_path, THRESHOLD)
+            .find_image_in_window(Some(rect), &default_path, state.arrow_threshold())
             .is_some()
         {
             state.update_progress();
@@ -802,10 +1214,13 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
                 println!("Broken pole detected -> pressing rods key");
                 SHARED_STATE.set_activity(BotActivity::HandlingBrokenRod);
                 SHARED_STATE.set_detail_message("Broken rod! Selecting new rod...");
+                utils::notifications::maybe_notify_broken_rod();
                 state.update_progress();
 
-                log_broken_rod();
+                let broken_rod_timestamp = log_broken_rod();
+                image_service.link_evidence_to_log(&broken_rod_timestamp);
                 tracing::debug!("[MAIN] Broken rod logged to file");
+                apply_rule_actions(&state);
 
                 if let Some(key) = get_pykey("rods_key") {
                     tracing::debug!("[MAIN] Pressing rods key: '{}'", key);
@@ -850,6 +1265,7 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
             SHARED_STATE.set_activity(BotActivity::CastingLine);
             SHARED_STATE.set_detail_message("Casting fishing line...");
 
+            press_sequence(Keybinds::load().get(FishingAction::Cast));
             click(center_x, center_y);
             println!("Started fishing -> waiting for catch_fish.png");
             state.update_progress();
@@ -864,13 +1280,51 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
             let mut wait_counter = 0;
             while state.is_running() {
                 wait_counter += 1;
+                state.drain_control_events();
 
-                if state.time_since_progress().as_secs() > NO_PROGRESS_LIMIT {
+                if state.module_enabled("NoProgressRecovery")
+                    && state.time_since_progress().as_secs() > state.no_progress_limit()
+                {
                     tracing::warn!("[MAIN] Timeout while waiting for fish!");
                     handle_no_progress_loop(&state, &image_service, &window_title);
                     break;
                 }
 
+                state.modules.lock().tick_enabled(&LoopContext {
+                    time_since_progress: state.time_since_progress(),
+                });
+
+                if bite_mode.uses_audio() {
+                    if let Some(true) = bite_listener.as_ref().map(|l| l.take_detected()) {
+                        tracing::info!("[MAIN] Fish detected via audio cue!");
+                        tracing::debug!("[MAIN] Waited {} iterations for fish to bite", wait_counter);
+                        state.update_progress();
+                        post_catch_loop(&state, &image_service, &fish_service, &window_title);
+                        break;
+                    }
+                }
+
+                // Skip the expensive template match entirely when the window region
+                // hasn't visibly changed since the last tick - large CPU savings
+                // while sitting idle waiting for a bite. Audio-only mode skips the
+                // image match regardless, since it never needs the capture at all.
+                let region_changed = bite_mode.uses_image()
+                    && image_service
+                        .capture_window_change_detection(Some(rect))
+                        .map(|r| r.changed)
+                        .unwrap_or(true);
+
+                if !region_changed {
+                    if wait_counter % 100 == 0 {
+                        tracing::trace!(
+                            "[MAIN] Still waiting for fish... iteration #{}",
+                            wait_counter
+                        );
+                    }
+                    thread::sleep(CHECK_INTERVAL);
+                    continue;
+                }
+
                 let catch_path = base
                     .join(TARGET_IMAGES_FOLDER)
                     .join(&res_folder)
@@ -907,6 +1361,24 @@ fn main_loop(state: Arc<MacroState>, image_service: ImageService, fish_service:
     }
 }
 
+/// Parse the `--ui <mode>`/`--ui=<mode>` CLI flag, defaulting to `"overlay"`
+/// when absent. `"tui"` selects the terminal dashboard (see the `tui`
+/// module); any other value keeps the default webview overlay.
+fn requested_ui_mode() -> String {
+    let args: Vec<String> = std::env::args().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if let Some(value) = arg.strip_prefix("--ui=") {
+            return value.to_string();
+        }
+        if arg == "--ui" {
+            if let Some(value) = args.get(i + 1) {
+                return value.clone();
+            }
+        }
+    }
+    "overlay".to_string()
+}
+
 fn main() {
     // Initialize logging
     tracing_subscriber::fmt::init();
@@ -927,17 +1399,23 @@ fn main() {
     fix_spelling();
 
     // Check for updates
-    tracing::info!("[INIT] Checking for updates...");
-    SHARED_STATE.set_detail_message("Checking for updates...");
-    if let Some(update) = check_for_update_blocking() {
-        tracing::info!("[INIT] New version available: {}", update.version);
-        println!("New version available: {}", update.version);
-        // In full implementation, would show update UI and download
-        // For now, just inform the user
-        println!("Please download the latest version from GitHub.");
+    if DEV_MODE {
+        tracing::info!("[INIT] Dev mode - skipping update check");
+        SHARED_STATE.set_update_status("uptodate", APP_VERSION, 0.0);
     } else {
-        tracing::info!("[INIT] App is up to date");
-        println!("App is up to date.");
+        tracing::info!("[INIT] Checking for updates...");
+        SHARED_STATE.set_detail_message("Checking for updates...");
+        SHARED_STATE.set_update_status("checking", APP_VERSION, 0.0);
+        if let Some(update) = check_for_update_blocking() {
+            tracing::info!("[INIT] New version available: {}", update.version);
+            println!("New version available: {}", update.version);
+            SHARED_STATE.set_update_status("available", &update.version, 0.0);
+            println!("Use the dashboard's update button to download it.");
+        } else {
+            tracing::info!("[INIT] App is up to date");
+            println!("App is up to date.");
+            SHARED_STATE.set_update_status("uptodate", APP_VERSION, 0.0);
+        }
     }
 
     // Initialize services
@@ -945,14 +1423,22 @@ fn main() {
     SHARED_STATE.set_detail_message("Loading configuration...");
     let base = get_data_dir();
     let config_path = base.join("config").join("fish_config.json");
-    tracing::debug!("[INIT] Config path: {:?}", config_path);
+    let fish_overrides_path = base.join("config").join("fish_overrides.json");
+    tracing::debug!(
+        "[INIT] Fish config sources: {:?}, {:?}",
+        config_path,
+        fish_overrides_path
+    );
 
-    let mut fish_service = FishService::new(config_path);
+    let mut fish_service = FishService::from_sources(vec![config_path, fish_overrides_path]);
     if let Err(e) = fish_service.load_fishes() {
         tracing::warn!("[INIT] Failed to load fish config: {}", e);
     } else {
         tracing::info!("[INIT] Fish config loaded successfully");
     }
+    if let Err(e) = fish_service.watch() {
+        tracing::warn!("[INIT] Failed to start fish config watcher: {}", e);
+    }
 
     tracing::debug!("[INIT] Creating ImageService...");
     let image_service = ImageService::new();
@@ -963,58 +1449,73 @@ fn main() {
     tracing::info!("[INIT] Setting up hotkey manager...");
     let manager = GlobalHotKeyManager::new().expect("Failed to create hotkey manager");
 
-    let (start_key_str, stop_key_str) = get_keys();
+    // Register every bound action's hotkey (start/stop plus pause,
+    // force-recovery, and reload-config) instead of hardcoding just
+    // start/stop - see `utils::keybinds::ACTION_BINDINGS`.
+    for (action, hotkey) in keybinds::registered_hotkeys() {
+        if let Err(e) = manager.register(hotkey) {
+            tracing::warn!("[INIT] Failed to register hotkey for {:?}: {}", action, e);
+        } else {
+            tracing::debug!("[INIT] Hotkey for {:?} registered successfully", action);
+        }
+    }
+
+    // Start remote monitoring/control server
     tracing::info!(
-        "[INIT] Hotkeys configured: START='{}', STOP='{}'",
-        start_key_str,
-        stop_key_str
+        "[INIT] Starting remote status server on port {}...",
+        STATUS_SERVER_PORT
     );
+    if let Err(e) = StatusServer::new(STATUS_SERVER_PORT).start() {
+        tracing::warn!("[INIT] Failed to start status server: {}", e);
+    }
 
-    // Register hotkeys
-    if let Some(start_code) = string_to_code(&start_key_str) {
-        let start_hotkey = HotKey::new(None, start_code);
-        if let Err(e) = manager.register(start_hotkey) {
-            tracing::warn!(
-                "[INIT] Failed to register start hotkey '{}': {}",
-                start_key_str,
-                e
-            );
-        } else {
-            tracing::debug!(
-                "[INIT] Start hotkey '{}' registered successfully",
-                start_key_str
-            );
-        }
-    } else {
-        tracing::warn!(
-            "[INIT] Could not convert start key '{}' to hotkey code",
-            start_key_str
-        );
+    // Start the headless HTTP/JSON telemetry endpoint alongside it, so
+    // external dashboards and stream overlays don't need a WebSocket client
+    // just to poll catch stats.
+    tracing::info!(
+        "[INIT] Starting telemetry server on port {}...",
+        TELEMETRY_SERVER_PORT
+    );
+    if let Err(e) = TelemetryServer::new(TELEMETRY_SERVER_PORT).start() {
+        tracing::warn!("[INIT] Failed to start telemetry server: {}", e);
     }
 
-    if let Some(stop_code) = string_to_code(&stop_key_str) {
-        let stop_hotkey = HotKey::new(None, stop_code);
-        if let Err(e) = manager.register(stop_hotkey) {
-            tracing::warn!(
-                "[INIT] Failed to register stop hotkey '{}': {}",
-                stop_key_str,
-                e
-            );
-        } else {
-            tracing::debug!(
-                "[INIT] Stop hotkey '{}' registered successfully",
-                stop_key_str
-            );
+    // Start the broadcast-based remote-control server alongside it, if enabled
+    let remote_control_settings = Settings::load();
+    if remote_control_settings.remote_control_enabled == "true" {
+        tracing::info!(
+            "[INIT] Starting remote control server on {}...",
+            remote_control_settings.remote_control_bind
+        );
+        if let Err(e) =
+            RemoteControlServer::new(remote_control_settings.remote_control_bind.clone()).start()
+        {
+            tracing::warn!("[INIT] Failed to start remote control server: {}", e);
         }
-    } else {
-        tracing::warn!(
-            "[INIT] Could not convert stop key '{}' to hotkey code",
-            stop_key_str
+    }
+
+    // Start the length-delimited raw-control server too, for clients that'd
+    // rather speak a plain framed TCP protocol than WebSocket.
+    if remote_control_settings.raw_control_enabled == "true" {
+        tracing::info!(
+            "[INIT] Starting raw control server on {}...",
+            remote_control_settings.raw_control_bind
         );
+        if let Err(e) = RawControlServer::new(remote_control_settings.raw_control_bind.clone()).start() {
+            tracing::warn!("[INIT] Failed to start raw control server: {}", e);
+        }
     }
 
-    // Clone state for hotkey handler
-    let state_clone = state.clone();
+    // Sample stats into the history ring buffer for the in-panel rate-over-time
+    // chart while a session is running, so the dashboard doesn't need its own
+    // polling timer to build the series.
+    const HISTORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+    thread::spawn(move || loop {
+        thread::sleep(HISTORY_SAMPLE_INTERVAL);
+        if SHARED_STATE.is_running() {
+            SHARED_STATE.sample_history();
+        }
+    });
 
     // Spawn hotkey listener thread
     tracing::info!("[INIT] Spawning hotkey listener thread...");
@@ -1025,21 +1526,42 @@ fn main() {
         loop {
             if let Ok(event) = receiver.recv() {
                 tracing::debug!("[HOTKEY] Hotkey event received: id={}", event.id);
-                let (start_str, stop_str) = get_keys();
 
-                if let Some(start_code) = string_to_code(&start_str) {
-                    let start_hotkey = HotKey::new(None, start_code);
-                    if event.id == start_hotkey.id() {
-                        tracing::info!("[HOTKEY] START key pressed");
-                        handle_start_key(&state_clone);
-                    }
-                }
+                let action = keybinds::registered_hotkeys()
+                    .into_iter()
+                    .find(|(_, hotkey)| hotkey.id() == event.id)
+                    .map(|(action, _)| action);
 
-                if let Some(stop_code) = string_to_code(&stop_str) {
-                    let stop_hotkey = HotKey::new(None, stop_code);
-                    if event.id == stop_hotkey.id() {
-                        tracing::info!("[HOTKEY] STOP key pressed");
-                        handle_stop_key(&state_clone);
+                match action {
+                    Some(keybinds::Action::Start) => {
+                        tracing::info!("[HOTKEY] START action triggered");
+                        events::send(BotEvent::StartRequested);
+                    }
+                    Some(keybinds::Action::Stop) => {
+                        tracing::info!("[HOTKEY] STOP action triggered");
+                        events::send(BotEvent::StopRequested);
+                    }
+                    Some(keybinds::Action::TogglePause) => {
+                        tracing::info!("[HOTKEY] TOGGLE PAUSE action triggered");
+                        control::send(ThreadControlEvent::TogglePause);
+                    }
+                    Some(keybinds::Action::ForceRecovery) => {
+                        tracing::info!("[HOTKEY] FORCE RECOVERY action triggered");
+                        control::send(ThreadControlEvent::RequestForceRecovery);
+                    }
+                    Some(keybinds::Action::ReloadConfig) => {
+                        tracing::info!("[HOTKEY] RELOAD CONFIG action triggered");
+                        events::send(BotEvent::ConfigUpdated);
+                    }
+                    Some(keybinds::Action::ToggleOverlay) => {
+                        // No real window-hide/show plumbing exists yet (see
+                        // `ui::ui_service::WindowHandle`'s placeholder comment) -
+                        // wired up here so it's a config/UI change, not a
+                        // hotkey-plumbing change, once that lands.
+                        tracing::info!("[HOTKEY] TOGGLE OVERLAY action triggered (not yet implemented)");
+                    }
+                    None => {
+                        tracing::debug!("[HOTKEY] Event id={} matched no registered action", event.id);
                     }
                 }
             }
@@ -1054,11 +1576,28 @@ fn main() {
     });
 
     // Start UI (blocks until UI closes)
-    tracing::info!("[INIT] Starting UI - this will block until UI closes");
     tracing::info!("========================================");
     tracing::info!("INITIALIZATION COMPLETE - Bot ready!");
     tracing::info!("========================================");
-    ui::start_ui();
+    if requested_ui_mode() == "tui" {
+        #[cfg(feature = "tui")]
+        {
+            tracing::info!("[INIT] Starting terminal UI - this will block until it exits");
+            if let Err(e) = tui::run_tui() {
+                tracing::error!("[INIT] Terminal UI error: {}", e);
+            }
+        }
+        #[cfg(not(feature = "tui"))]
+        {
+            tracing::warn!(
+                "[INIT] --ui tui was requested but this build doesn't have the `tui` feature enabled; falling back to the overlay"
+            );
+            ui::start_ui();
+        }
+    } else {
+        tracing::info!("[INIT] Starting UI - this will block until UI closes");
+        ui::start_ui();
+    }
 
     // Cleanup
     tracing::info!("[SHUTDOWN] UI closed, starting cleanup...");
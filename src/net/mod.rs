@@ -0,0 +1,13 @@
+//! Network subsystem for remote monitoring and control
+
+#![allow(dead_code)]
+
+pub mod raw_control;
+pub mod remote_control;
+pub mod status_server;
+pub mod telemetry_server;
+
+pub use raw_control::RawControlServer;
+pub use remote_control::RemoteControlServer;
+pub use status_server::StatusServer;
+pub use telemetry_server::TelemetryServer;
@@ -0,0 +1,306 @@
+//! Length-delimited raw-TCP monitoring and control server, for clients that
+//! don't want to speak WebSocket (e.g. a phone app talking a plain socket).
+//!
+//! Framing mirrors rustdesk's `bytes_codec`: each frame is a 4-byte
+//! big-endian length prefix followed by a 1-byte compression flag and the
+//! (optionally zlib-compressed, like `screen_reader::debug_capture`'s PNG
+//! writer) JSON payload, with the length prefix capped at `MAX_FRAME_LEN`
+//! like rustdesk's codec caps it - an attacker-controlled length otherwise
+//! forces an allocation of whatever it says. Like `RemoteControlServer`, a
+//! connected client gets an immediate snapshot plus a push every time
+//! `SHARED_STATE` changes (subscribing to the same `event_bus`), and can
+//! send back inbound `{"action":"start"|"stop"|"force_recovery"}` frames.
+
+#![allow(dead_code)]
+
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use crate::utils::bot_state::SHARED_STATE;
+use crate::utils::control::{self, ThreadControlEvent};
+use crate::utils::event_bus;
+use crate::utils::events::{self, BotEvent};
+
+type Client = Arc<Mutex<TcpStream>>;
+
+/// Payloads at or above this size get zlib-compressed before framing;
+/// smaller ones (most status pushes) aren't worth the round trip.
+const COMPRESS_THRESHOLD: usize = 256;
+
+/// Maximum accepted frame length, in bytes, mirroring `ipc_guard`'s
+/// `MAX_MESSAGE_LEN` guard for the webview IPC. Generous for the largest
+/// legitimate payload (a `bot-status` snapshot) with headroom, far below a
+/// length prefix an attacker controls being used to force a multi-GB
+/// allocation.
+const MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+/// Raw length-delimited control server broadcasting `bot-status` changes to
+/// every connected client and applying inbound start/stop/force-recovery
+/// frames.
+pub struct RawControlServer {
+    bind_addr: String,
+}
+
+impl RawControlServer {
+    /// Create a server configured to bind `bind_addr` (e.g. `"127.0.0.1:9014"`).
+    /// Call `start` to actually listen.
+    pub fn new(bind_addr: impl Into<String>) -> Self {
+        Self {
+            bind_addr: bind_addr.into(),
+        }
+    }
+
+    /// Bind the configured address and spawn the accept loop and the
+    /// broadcast loop as background threads.
+    pub fn start(&self) -> io::Result<()> {
+        let listener = TcpListener::bind(&self.bind_addr)?;
+        tracing::info!("[NET] RawControlServer listening on {}", self.bind_addr);
+
+        let clients: Arc<Mutex<Vec<Client>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let broadcast_clients = clients.clone();
+        thread::spawn(move || broadcast_loop(broadcast_clients));
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        let clients = clients.clone();
+                        thread::spawn(move || handle_client(stream, clients));
+                    }
+                    Err(e) => {
+                        tracing::warn!("[NET] RawControlServer: failed to accept: {}", e);
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Write one length-delimited frame: `[u32 len][u8 flag][body]`, compressing
+/// `payload` first when it's at least `COMPRESS_THRESHOLD` bytes.
+fn write_frame(stream: &mut TcpStream, payload: &[u8]) -> io::Result<()> {
+    let (flag, body): (u8, Vec<u8>) = if payload.len() >= COMPRESS_THRESHOLD {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(payload)?;
+        (1, encoder.finish()?)
+    } else {
+        (0, payload.to_vec())
+    };
+
+    let len = (body.len() + 1) as u32;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(&[flag])?;
+    stream.write_all(&body)
+}
+
+/// Read one length-delimited frame, decompressing it if its flag byte says
+/// it was compressed. Returns `Ok(None)` on a clean disconnect.
+fn read_frame(stream: &mut TcpStream) -> io::Result<Option<Vec<u8>>> {
+    let mut len_buf = [0u8; 4];
+    match stream.read_exact(&mut len_buf) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len == 0 {
+        return Ok(Some(Vec::new()));
+    }
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame length {} exceeds MAX_FRAME_LEN ({})", len, MAX_FRAME_LEN),
+        ));
+    }
+
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body)?;
+    let (flag, payload) = (body[0], &body[1..]);
+
+    if flag == 1 {
+        let mut out = Vec::new();
+        ZlibDecoder::new(payload).read_to_end(&mut out)?;
+        Ok(Some(out))
+    } else {
+        Ok(Some(payload.to_vec()))
+    }
+}
+
+/// Subscribe to the event bus and frame every `bot-status` event out to all
+/// currently connected clients, tagged with a monotonically increasing
+/// `seq` so clients can tell frames apart and detect any drops.
+fn broadcast_loop(clients: Arc<Mutex<Vec<Client>>>) {
+    let seq = AtomicU64::new(0);
+    for event in event_bus::subscribe() {
+        if event.name != "bot-status" {
+            continue;
+        }
+
+        let frame = serde_json::json!({
+            "type": "update",
+            "seq": seq.fetch_add(1, Ordering::SeqCst),
+            "status": event.payload,
+        });
+
+        let Ok(bytes) = serde_json::to_vec(&frame) else {
+            continue;
+        };
+        broadcast(&clients, &bytes);
+    }
+}
+
+/// Write `payload` as a frame to every connected client, dropping any that
+/// error out.
+fn broadcast(clients: &Arc<Mutex<Vec<Client>>>, payload: &[u8]) {
+    let mut clients = clients.lock().unwrap();
+    clients.retain(|client| write_frame(&mut client.lock().unwrap(), payload).is_ok());
+}
+
+/// Send a new client an immediate snapshot, register it for future
+/// broadcasts, then block reading inbound control frames until it
+/// disconnects.
+fn handle_client(stream: TcpStream, clients: Arc<Mutex<Vec<Client>>>) {
+    let peer = stream
+        .peer_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let mut reader = match stream.try_clone() {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::warn!("[NET] RawControlServer: failed to clone stream for {}: {}", peer, e);
+            return;
+        }
+    };
+
+    let client: Client = Arc::new(Mutex::new(stream));
+
+    let snapshot = serde_json::json!({
+        "type": "snapshot",
+        "status": SHARED_STATE.status_value(),
+    });
+    let Ok(snapshot_bytes) = serde_json::to_vec(&snapshot) else {
+        return;
+    };
+    if write_frame(&mut client.lock().unwrap(), &snapshot_bytes).is_err() {
+        tracing::debug!("[NET] RawControlServer: failed to send snapshot to {}", peer);
+        return;
+    }
+
+    tracing::info!("[NET] RawControlServer: client connected: {}", peer);
+    clients.lock().unwrap().push(client.clone());
+
+    loop {
+        match read_frame(&mut reader) {
+            Ok(Some(bytes)) => apply_control_message(&bytes),
+            Ok(None) => break,
+            Err(e) => {
+                tracing::debug!("[NET] RawControlServer: client {} error: {}", peer, e);
+                break;
+            }
+        }
+    }
+
+    tracing::debug!("[NET] RawControlServer: client disconnected: {}", peer);
+    clients.lock().unwrap().retain(|c| !Arc::ptr_eq(c, &client));
+}
+
+/// Apply an inbound `{"action":"start"|"stop"|"force_recovery"}` control
+/// frame, posting the same events the hotkey thread and UI do.
+fn apply_control_message(bytes: &[u8]) {
+    let Ok(value) = serde_json::from_slice::<serde_json::Value>(bytes) else {
+        tracing::debug!("[NET] RawControlServer: ignoring non-JSON frame ({} bytes)", bytes.len());
+        return;
+    };
+
+    match value.get("action").and_then(|a| a.as_str()) {
+        Some("start") => {
+            tracing::info!("[NET] RawControlServer: start");
+            events::send(BotEvent::StartRequested);
+        }
+        Some("stop") => {
+            tracing::info!("[NET] RawControlServer: stop");
+            events::send(BotEvent::StopRequested);
+        }
+        Some("force_recovery") => {
+            tracing::info!("[NET] RawControlServer: force_recovery");
+            control::send(ThreadControlEvent::RequestForceRecovery);
+        }
+        other => {
+            tracing::debug!("[NET] RawControlServer: ignoring unknown action: {:?}", other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_then_read_frame_roundtrip_small_payload() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let writer_thread = thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write_frame(&mut stream, b"{\"action\":\"start\"}").unwrap();
+        });
+
+        let (mut server_stream, _) = listener.accept().unwrap();
+        let frame = read_frame(&mut server_stream).unwrap().unwrap();
+        assert_eq!(frame, b"{\"action\":\"start\"}");
+
+        writer_thread.join().unwrap();
+    }
+
+    #[test]
+    fn test_write_then_read_frame_roundtrip_compressed_payload() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let payload = "x".repeat(COMPRESS_THRESHOLD + 1);
+        let payload_clone = payload.clone();
+        let writer_thread = thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write_frame(&mut stream, payload_clone.as_bytes()).unwrap();
+        });
+
+        let (mut server_stream, _) = listener.accept().unwrap();
+        let frame = read_frame(&mut server_stream).unwrap().unwrap();
+        assert_eq!(frame, payload.as_bytes());
+
+        writer_thread.join().unwrap();
+    }
+
+    #[test]
+    fn test_raw_control_server_new_stores_bind_addr() {
+        let server = RawControlServer::new("127.0.0.1:9014");
+        assert_eq!(server.bind_addr, "127.0.0.1:9014");
+    }
+
+    #[test]
+    fn test_read_frame_rejects_oversized_length_prefix() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let writer_thread = thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            let len = (MAX_FRAME_LEN + 1) as u32;
+            stream.write_all(&len.to_be_bytes()).unwrap();
+        });
+
+        let (mut server_stream, _) = listener.accept().unwrap();
+        let err = read_frame(&mut server_stream).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        writer_thread.join().unwrap();
+    }
+}
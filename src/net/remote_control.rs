@@ -0,0 +1,214 @@
+//! WebSocket remote-control and live-telemetry server mirroring the local
+//! dashboard's IPC contract, for clients that aren't the embedded webview.
+//!
+//! Unlike `StatusServer`'s fixed-interval per-client tick, this broadcasts a
+//! snapshot to every connected client as soon as `SHARED_STATE` actually
+//! changes (subscribing to the same `event_bus` the GUI event loop listens
+//! on), sends a snapshot immediately on connect so new clients don't have to
+//! wait for the next change, and tags every broadcast frame with a monotonic
+//! sequence number so clients can detect drops. Inbound frames are JSON
+//! actions (`{"action":"start"}` / `{"action":"stop"}`), the same shape the
+//! webview bridge sends, rather than `StatusServer`'s plain-text commands.
+
+#![allow(dead_code)]
+
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tungstenite::{Message, WebSocket};
+
+use crate::utils::bot_state::SHARED_STATE;
+use crate::utils::event_bus;
+
+type Client = Arc<Mutex<WebSocket<TcpStream>>>;
+
+/// Remote-control server broadcasting `bot-status` changes to every connected
+/// client and applying inbound `{"action":"start"|"stop"}` frames.
+pub struct RemoteControlServer {
+    bind_addr: String,
+}
+
+impl RemoteControlServer {
+    /// Create a server configured to bind `bind_addr` (e.g. `"127.0.0.1:9013"`).
+    /// Call `start` to actually listen.
+    pub fn new(bind_addr: impl Into<String>) -> Self {
+        Self {
+            bind_addr: bind_addr.into(),
+        }
+    }
+
+    /// Bind the configured address and spawn the accept loop and the
+    /// broadcast loop as background threads.
+    pub fn start(&self) -> io::Result<()> {
+        let listener = TcpListener::bind(&self.bind_addr)?;
+        tracing::info!(
+            "[NET] RemoteControlServer listening on {}",
+            self.bind_addr
+        );
+
+        let clients: Arc<Mutex<Vec<Client>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let broadcast_clients = clients.clone();
+        thread::spawn(move || broadcast_loop(broadcast_clients));
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        let clients = clients.clone();
+                        thread::spawn(move || handle_client(stream, clients));
+                    }
+                    Err(e) => {
+                        tracing::warn!("[NET] RemoteControlServer: failed to accept: {}", e);
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Subscribe to the event bus and forward every `bot-status` event to all
+/// currently connected clients, wrapping it with a `type` tag and a
+/// monotonically increasing `seq` so clients can tell frames apart from the
+/// initial snapshot and detect any drops.
+fn broadcast_loop(clients: Arc<Mutex<Vec<Client>>>) {
+    let seq = AtomicU64::new(0);
+    for event in event_bus::subscribe() {
+        if event.name != "bot-status" {
+            continue;
+        }
+
+        let frame = serde_json::json!({
+            "type": "update",
+            "seq": seq.fetch_add(1, Ordering::SeqCst),
+            "status": event.payload,
+        })
+        .to_string();
+
+        broadcast(&clients, &frame);
+    }
+}
+
+/// Send `frame` to every connected client, dropping any that error out.
+fn broadcast(clients: &Arc<Mutex<Vec<Client>>>, frame: &str) {
+    let mut clients = clients.lock().unwrap();
+    clients.retain(|client| {
+        client
+            .lock()
+            .unwrap()
+            .send(Message::Text(frame.to_string()))
+            .is_ok()
+    });
+}
+
+/// Upgrade a raw TCP connection to a WebSocket, send it an immediate
+/// snapshot, register it for future broadcasts, then block reading inbound
+/// control actions until the client disconnects.
+fn handle_client(stream: TcpStream, clients: Arc<Mutex<Vec<Client>>>) {
+    let peer = stream
+        .peer_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let socket = match tungstenite::accept(stream) {
+        Ok(ws) => ws,
+        Err(e) => {
+            tracing::warn!(
+                "[NET] RemoteControlServer: handshake failed for {}: {}",
+                peer,
+                e
+            );
+            return;
+        }
+    };
+
+    let client: Client = Arc::new(Mutex::new(socket));
+
+    let snapshot = serde_json::json!({
+        "type": "snapshot",
+        "status": SHARED_STATE.status_value(),
+    })
+    .to_string();
+
+    if client.lock().unwrap().send(Message::Text(snapshot)).is_err() {
+        tracing::debug!(
+            "[NET] RemoteControlServer: failed to send snapshot to {}",
+            peer
+        );
+        return;
+    }
+
+    tracing::info!("[NET] RemoteControlServer: client connected: {}", peer);
+    clients.lock().unwrap().push(client.clone());
+
+    loop {
+        let message = client.lock().unwrap().read();
+        match message {
+            Ok(Message::Text(text)) => apply_control_message(&text),
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!("[NET] RemoteControlServer: client {} error: {}", peer, e);
+                break;
+            }
+        }
+    }
+
+    tracing::debug!("[NET] RemoteControlServer: client disconnected: {}", peer);
+    clients.lock().unwrap().retain(|c| !Arc::ptr_eq(c, &client));
+}
+
+/// Apply an inbound `{"action":"start"|"stop"}` control frame to the shared
+/// bot state, mirroring the webview's own start/stop actions.
+fn apply_control_message(text: &str) {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        tracing::debug!("[NET] RemoteControlServer: ignoring non-JSON frame: {:?}", text);
+        return;
+    };
+
+    match value.get("action").and_then(|a| a.as_str()) {
+        Some("start") => {
+            tracing::info!("[NET] RemoteControlServer: start");
+            SHARED_STATE.set_running(true);
+        }
+        Some("stop") => {
+            tracing::info!("[NET] RemoteControlServer: stop");
+            SHARED_STATE.set_running(false);
+        }
+        other => {
+            tracing::debug!("[NET] RemoteControlServer: ignoring unknown action: {:?}", other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_control_message_start_stop() {
+        apply_control_message(r#"{"action":"start"}"#);
+        assert!(SHARED_STATE.is_running());
+
+        apply_control_message(r#"{"action":"stop"}"#);
+        assert!(!SHARED_STATE.is_running());
+    }
+
+    #[test]
+    fn test_apply_control_message_ignores_garbage() {
+        SHARED_STATE.set_running(false);
+        apply_control_message("not json");
+        assert!(!SHARED_STATE.is_running());
+    }
+
+    #[test]
+    fn test_remote_control_server_new_stores_bind_addr() {
+        let server = RemoteControlServer::new("127.0.0.1:9013");
+        assert_eq!(server.bind_addr, "127.0.0.1:9013");
+    }
+}
@@ -0,0 +1,171 @@
+//! WebSocket status server for remote monitoring and control
+
+#![allow(dead_code)]
+
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use tungstenite::{Message, WebSocket};
+
+use crate::utils::bot_state::SHARED_STATE;
+
+/// How often a connected client receives a status/detection-box push.
+const TICK_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Remote monitoring and control server. Streams `SharedBotState` snapshots
+/// (`to_json`/`detection_boxes_to_json`) to every connected WebSocket client at a
+/// fixed tick, and applies inbound control messages (`start`, `stop`,
+/// `reset_stats`) to the shared state.
+pub struct StatusServer {
+    port: u16,
+}
+
+impl StatusServer {
+    /// Create a server configured to bind `port`. Call `start` to actually listen.
+    pub fn new(port: u16) -> Self {
+        Self { port }
+    }
+
+    /// Bind the configured port on loopback only and spawn a background
+    /// thread that accepts clients, handing each one off to its own handler
+    /// thread. Loopback-only because inbound control messages
+    /// (`start`/`stop`/`reset_stats`) are unauthenticated - binding the LAN
+    /// interface by default would let any other host on the network drive
+    /// the bot.
+    pub fn start(&self) -> io::Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", self.port))?;
+        tracing::info!("[NET] StatusServer listening on port {}", self.port);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        thread::spawn(move || handle_client(stream));
+                    }
+                    Err(e) => {
+                        tracing::warn!("[NET] Failed to accept connection: {}", e);
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Upgrade a raw TCP connection to a WebSocket, then loop: drain any inbound
+/// control message, push a status tick, repeat.
+fn handle_client(stream: TcpStream) {
+    let peer = stream
+        .peer_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let mut socket = match tungstenite::accept(stream) {
+        Ok(ws) => ws,
+        Err(e) => {
+            tracing::warn!("[NET] WebSocket handshake failed for {}: {}", peer, e);
+            return;
+        }
+    };
+
+    if let Err(e) = socket.get_mut().set_read_timeout(Some(TICK_INTERVAL)) {
+        tracing::warn!("[NET] Failed to set read timeout for {}: {}", peer, e);
+    }
+
+    tracing::info!("[NET] Client connected: {}", peer);
+
+    loop {
+        if !drain_control_messages(&mut socket) {
+            tracing::debug!("[NET] Client disconnected: {}", peer);
+            return;
+        }
+
+        let payload = format!(
+            "{{\"status\":{},\"detections\":{}}}",
+            SHARED_STATE.to_json(),
+            SHARED_STATE.detection_boxes_to_json()
+        );
+
+        if socket.send(Message::Text(payload)).is_err() {
+            tracing::debug!("[NET] Failed to send status tick, dropping client: {}", peer);
+            return;
+        }
+    }
+}
+
+/// Read and apply any control message waiting on the socket. Returns `false` if
+/// the client closed the connection or the socket errored out.
+fn drain_control_messages(socket: &mut WebSocket<TcpStream>) -> bool {
+    match socket.read() {
+        Ok(Message::Text(text)) => {
+            apply_control_message(&text);
+            true
+        }
+        Ok(Message::Close(_)) => false,
+        Ok(_) => true,
+        Err(tungstenite::Error::Io(ref e))
+            if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut =>
+        {
+            // No inbound message within this tick - that's fine, keep ticking.
+            true
+        }
+        Err(e) => {
+            tracing::debug!("[NET] WebSocket error: {}", e);
+            false
+        }
+    }
+}
+
+/// Apply an inbound control command (`start`, `stop`, `reset_stats`) to the
+/// shared bot state.
+fn apply_control_message(text: &str) {
+    match text.trim() {
+        "start" => {
+            tracing::info!("[NET] Remote control: start");
+            SHARED_STATE.set_running(true);
+        }
+        "stop" => {
+            tracing::info!("[NET] Remote control: stop");
+            SHARED_STATE.set_running(false);
+        }
+        "reset_stats" => {
+            tracing::info!("[NET] Remote control: reset_stats");
+            SHARED_STATE.reset_stats();
+        }
+        other => {
+            tracing::debug!("[NET] Ignoring unknown control message: {:?}", other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_control_message_start_stop() {
+        apply_control_message("start");
+        assert!(SHARED_STATE.is_running());
+
+        apply_control_message("stop");
+        assert!(!SHARED_STATE.is_running());
+    }
+
+    #[test]
+    fn test_apply_control_message_reset_stats() {
+        SHARED_STATE.update_stats(5, 2, 100);
+        apply_control_message("reset_stats");
+        let stats = SHARED_STATE.get_stats();
+        assert_eq!(stats.catches, 0);
+        assert_eq!(stats.misses, 0);
+    }
+
+    #[test]
+    fn test_status_server_new_stores_port() {
+        let server = StatusServer::new(9012);
+        assert_eq!(server.port, 9012);
+    }
+}
@@ -0,0 +1,167 @@
+//! Local HTTP/JSON telemetry endpoint with conditional polling.
+//!
+//! Exposes `SHARED_STATE` (activity, detail message, stats, detection lane)
+//! over a tiny hand-rolled HTTP server, so external dashboards, stream
+//! overlays, or phones can watch the bot without going through the in-process
+//! WebView. A polling client sends its last-seen `updated_at` as
+//! `?since=<rfc3339>`; if nothing has changed since then, the server replies
+//! `304 Not Modified` with an empty body instead of a fresh JSON payload, so
+//! neither side redraws for nothing.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use crate::utils::bot_state::SHARED_STATE;
+
+/// Serves `GET /status[?since=<rfc3339>]` on its own background thread, one
+/// short-lived handler thread per connection - mirrors `StatusServer`'s
+/// accept loop, just plain HTTP instead of a WebSocket upgrade.
+pub struct TelemetryServer {
+    port: u16,
+}
+
+impl TelemetryServer {
+    /// Create a server configured to bind `port`. Call `start` to actually listen.
+    pub fn new(port: u16) -> Self {
+        Self { port }
+    }
+
+    /// Bind the configured port and spawn a background thread that accepts
+    /// connections, handing each one off to its own handler thread.
+    pub fn start(&self) -> std::io::Result<()> {
+        let listener = TcpListener::bind(("0.0.0.0", self.port))?;
+        tracing::info!("[NET] TelemetryServer listening on port {}", self.port);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        thread::spawn(move || handle_request(stream));
+                    }
+                    Err(e) => {
+                        tracing::warn!("[NET] Failed to accept telemetry connection: {}", e);
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Read one request, reply with a JSON snapshot (or `304` if the caller's
+/// `since` matches the current `updated_at`), then close the connection -
+/// this endpoint is poll-once-per-request, not a persistent stream.
+fn handle_request(stream: TcpStream) {
+    let peer = stream
+        .peer_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let mut reader = match stream.try_clone() {
+        Ok(s) => BufReader::new(s),
+        Err(e) => {
+            tracing::warn!("[NET] Failed to clone telemetry stream for {}: {}", peer, e);
+            return;
+        }
+    };
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() || request_line.is_empty() {
+        return;
+    }
+
+    // Drain and discard the remaining request headers - this endpoint has no
+    // use for them, but the client expects them read before it gets a reply.
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) if line.trim().is_empty() => break,
+            Ok(_) => continue,
+            Err(_) => break,
+        }
+    }
+
+    let mut stream = stream;
+    let since = parse_since(&request_line);
+
+    if since.as_deref() == Some(SHARED_STATE.updated_at().as_str()) {
+        tracing::debug!("[NET] Telemetry poll from {}: no change", peer);
+        let _ = stream.write_all(b"HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n");
+        return;
+    }
+
+    let body = SHARED_STATE.to_json();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Pull `since` out of a request line like `GET /status?since=... HTTP/1.1`.
+fn parse_since(request_line: &str) -> Option<String> {
+    let path = request_line.split_whitespace().nth(1)?;
+    let query = path.split_once('?')?.1;
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key == "since").then(|| decode_percent(value))
+    })
+}
+
+/// Minimal `%XX`/`+` percent-decoding - just enough for the `:`, `+`, and `.`
+/// an RFC3339 timestamp picks up when URL-encoded by a well-behaved client.
+fn decode_percent(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b'%' if i + 2 < bytes.len() => {
+                if let Ok(byte) = u8::from_str_radix(&value[i + 1..i + 3], 16) {
+                    out.push(byte);
+                    i += 3;
+                } else {
+                    out.push(bytes[i]);
+                    i += 1;
+                }
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_since_decodes_colon_and_plus() {
+        let line = "GET /status?since=2026-07-26T12%3A00%3A00%2B00%3A00 HTTP/1.1\r\n";
+        assert_eq!(parse_since(line), Some("2026-07-26T12:00:00+00:00".to_string()));
+    }
+
+    #[test]
+    fn test_parse_since_missing_query_returns_none() {
+        assert_eq!(parse_since("GET /status HTTP/1.1\r\n"), None);
+    }
+
+    #[test]
+    fn test_telemetry_server_new_stores_port() {
+        let server = TelemetryServer::new(9013);
+        assert_eq!(server.port, 9013);
+    }
+}
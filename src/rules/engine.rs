@@ -0,0 +1,301 @@
+//! Rule engine for reacting to live fishing telemetry
+//!
+//! Rules are loaded from `rules.json` as plain strings of comma-separated
+//! clauses: `metric OP value : action, metric OP value : action, ..., default`.
+//! The first clause whose condition holds fires; if none match, the trailing
+//! bare default action fires. Example:
+//! `catch_rate<40:stop, broken_rods>3:press_key:rods_key, fish_per_min<0.5:switch_bait, start`
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::ui::stats_api::FishStats;
+use crate::utils::path::get_data_dir;
+
+/// Telemetry a rule's condition can be evaluated against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Metric {
+    CatchRate,
+    BrokenRods,
+    FishPerMin,
+    FailCount,
+}
+
+impl Metric {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "catch_rate" => Some(Metric::CatchRate),
+            "broken_rods" => Some(Metric::BrokenRods),
+            "fish_per_min" => Some(Metric::FishPerMin),
+            "fail_count" => Some(Metric::FailCount),
+            _ => None,
+        }
+    }
+}
+
+/// Comparison operator for a rule clause's condition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Eq,
+}
+
+impl Op {
+    fn apply(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Op::Lt => lhs < rhs,
+            Op::Gt => lhs > rhs,
+            Op::Le => lhs <= rhs,
+            Op::Ge => lhs >= rhs,
+            Op::Eq => (lhs - rhs).abs() < f64::EPSILON,
+        }
+    }
+}
+
+/// An action a fired rule requests of the bot loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Stop,
+    Start,
+    /// Press the keybind named by config key (e.g. `"rods_key"`), resolved
+    /// through `get_pykey` by the caller.
+    PressKey(String),
+}
+
+#[derive(Debug, Clone)]
+struct Clause {
+    metric: Metric,
+    op: Op,
+    value: f64,
+    action: Action,
+}
+
+/// A single automation rule: an ordered list of guarded clauses plus a
+/// trailing default action.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    clauses: Vec<Clause>,
+    default: Action,
+}
+
+impl Rule {
+    /// Parse a rule string of comma-separated `metric OP value : action`
+    /// clauses ending in a bare default action. Returns `None` if the rule
+    /// has no default action or any clause fails to parse.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let segments: Vec<&str> = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect();
+        let (default_str, clause_strs) = segments.split_last()?;
+        let default = parse_action(default_str)?;
+
+        let mut clauses = Vec::with_capacity(clause_strs.len());
+        for clause_str in clause_strs {
+            let (condition, action_str) = clause_str.split_once(':')?;
+            let (metric, op, value) = parse_condition(condition)?;
+            let action = parse_action(action_str)?;
+            clauses.push(Clause {
+                metric,
+                op,
+                value,
+                action,
+            });
+        }
+
+        Some(Self { clauses, default })
+    }
+
+    /// Evaluate the rule against `metrics`, returning the first clause whose
+    /// condition holds, or the default action if none do.
+    pub fn evaluate(&self, metrics: &RuleMetrics) -> Action {
+        for clause in &self.clauses {
+            if clause.op.apply(metrics.value_of(clause.metric), clause.value) {
+                return clause.action.clone();
+            }
+        }
+        self.default.clone()
+    }
+}
+
+fn parse_condition(s: &str) -> Option<(Metric, Op, f64)> {
+    const OPS: [(&str, Op); 5] = [
+        ("<=", Op::Le),
+        (">=", Op::Ge),
+        ("==", Op::Eq),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+    ];
+
+    for (token, op) in OPS {
+        if let Some(idx) = s.find(token) {
+            let metric = Metric::parse(s[..idx].trim())?;
+            let value: f64 = s[idx + token.len()..].trim().parse().ok()?;
+            return Some((metric, op, value));
+        }
+    }
+    None
+}
+
+fn parse_action(s: &str) -> Option<Action> {
+    match s.trim() {
+        "stop" => Some(Action::Stop),
+        "start" => Some(Action::Start),
+        "switch_bait" => Some(Action::PressKey("bait_key".to_string())),
+        other => other
+            .strip_prefix("press_key:")
+            .map(|key| Action::PressKey(key.trim().to_string())),
+    }
+}
+
+/// Live telemetry snapshot a `Rule` is evaluated against, sourced from
+/// `FishStats`'s most recent session window.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleMetrics {
+    pub catch_rate: f64,
+    pub broken_rods: i32,
+    pub fish_per_min: f64,
+    pub fail_count: i32,
+}
+
+impl RuleMetrics {
+    fn value_of(&self, metric: Metric) -> f64 {
+        match metric {
+            Metric::CatchRate => self.catch_rate,
+            Metric::BrokenRods => self.broken_rods as f64,
+            Metric::FishPerMin => self.fish_per_min,
+            Metric::FailCount => self.fail_count as f64,
+        }
+    }
+
+    /// Build metrics from `stats`'s most recent fishing session.
+    fn from_stats(stats: &FishStats) -> Self {
+        let sessions = stats.get_sessions();
+        let latest = sessions.last();
+        let catches = latest.map(|s| s.catches).unwrap_or(0);
+        let fails = latest.map(|s| s.fails).unwrap_or(0);
+        let total = catches + fails;
+
+        Self {
+            catch_rate: if total > 0 {
+                (catches as f64 / total as f64) * 100.0
+            } else {
+                0.0
+            },
+            broken_rods: stats.get_broken_rod_count(),
+            fish_per_min: latest.map(|s| s.fish_per_min()).unwrap_or(0.0),
+            fail_count: fails,
+        }
+    }
+}
+
+/// Path to the rules config file.
+fn get_rules_path() -> PathBuf {
+    get_data_dir().join("config").join("rules.json")
+}
+
+/// Loads `rules.json` and evaluates each rule against live `FishStats`
+/// telemetry every time stats refresh.
+pub struct RuleEngine {
+    rules: Vec<Rule>,
+}
+
+impl RuleEngine {
+    /// Load rules from `rules.json`. A missing file or unparsable entry is
+    /// skipped rather than treated as a hard error, consistent with the
+    /// bot's other best-effort config loaders.
+    pub fn load() -> Self {
+        let path = get_rules_path();
+        let specs: Vec<String> = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        let rules = specs.iter().filter_map(|s| Rule::parse(s)).collect();
+        Self { rules }
+    }
+
+    /// Evaluate every loaded rule against `stats`'s most recent window,
+    /// returning the fired action for each rule in order.
+    pub fn evaluate_rules(&self, stats: &FishStats) -> Vec<Action> {
+        let metrics = RuleMetrics::from_stats(stats);
+        self.rules.iter().map(|r| r.evaluate(&metrics)).collect()
+    }
+}
+
+impl Default for RuleEngine {
+    fn default() -> Self {
+        Self::load()
+    }
+}
+
+/// Load `rules.json` and evaluate its rules against `stats`'s most recent
+/// window, returning one fired action per loaded rule so the bot loop can
+/// act on broken-rod bursts or collapsing catch rates without manual
+/// intervention.
+pub fn evaluate_rules(stats: &FishStats) -> Vec<Action> {
+    RuleEngine::load().evaluate_rules(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule_and_evaluate_first_match() {
+        let rule = Rule::parse("catch_rate<40:stop,broken_rods>3:press_key:rods_key,start")
+            .expect("rule should parse");
+
+        let metrics = RuleMetrics {
+            catch_rate: 30.0,
+            broken_rods: 0,
+            fish_per_min: 1.0,
+            fail_count: 2,
+        };
+        assert_eq!(rule.evaluate(&metrics), Action::Stop);
+
+        let metrics = RuleMetrics {
+            catch_rate: 90.0,
+            broken_rods: 5,
+            fish_per_min: 1.0,
+            fail_count: 0,
+        };
+        assert_eq!(
+            rule.evaluate(&metrics),
+            Action::PressKey("rods_key".to_string())
+        );
+
+        let metrics = RuleMetrics {
+            catch_rate: 90.0,
+            broken_rods: 0,
+            fish_per_min: 1.0,
+            fail_count: 0,
+        };
+        assert_eq!(rule.evaluate(&metrics), Action::Start);
+    }
+
+    #[test]
+    fn test_parse_switch_bait_action() {
+        let rule = Rule::parse("fish_per_min<0.5:switch_bait,start").unwrap();
+        let metrics = RuleMetrics {
+            fish_per_min: 0.1,
+            ..Default::default()
+        };
+        assert_eq!(
+            rule.evaluate(&metrics),
+            Action::PressKey("bait_key".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_rule() {
+        assert!(Rule::parse("catch_rate?40:stop,start").is_none());
+        assert!(Rule::parse("").is_none());
+    }
+}
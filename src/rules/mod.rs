@@ -0,0 +1,7 @@
+//! Condition-driven automation rule engine
+
+#![allow(dead_code)]
+
+pub mod engine;
+
+pub use engine::{evaluate_rules, Action, Rule, RuleEngine, RuleMetrics};
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use std::fs;
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::utils::config_format::ConfigFormat;
 use crate::utils::path::get_data_dir;
 
 /// Default settings
@@ -20,6 +21,14 @@ pub static DEFAULT_SETTINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
     m.insert("bait_key".to_string(), "N".to_string());
     m.insert("fish_key".to_string(), "F".to_string());
     m.insert("esc_key".to_string(), "ESC".to_string());
+    m.insert("remote_control_enabled".to_string(), "false".to_string());
+    m.insert("remote_control_bind".to_string(), "127.0.0.1:9013".to_string());
+    m.insert("raw_control_enabled".to_string(), "false".to_string());
+    m.insert("raw_control_bind".to_string(), "127.0.0.1:9014".to_string());
+    m.insert("bite_detection_mode".to_string(), "image".to_string());
+    m.insert("bite_audio_reference_clip".to_string(), "audio/bite.wav".to_string());
+    m.insert("bite_audio_threshold".to_string(), "0.35".to_string());
+    m.insert("capture_backend".to_string(), "direct".to_string());
     m
 });
 
@@ -35,6 +44,32 @@ pub struct Settings {
     pub bait_key: String,
     pub fish_key: String,
     pub esc_key: String,
+    /// Whether `net::remote_control::RemoteControlServer` should be started.
+    pub remote_control_enabled: String,
+    /// Bind address for the remote-control server, e.g. `"127.0.0.1:9013"`.
+    /// Defaults to loopback-only: it applies unauthenticated
+    /// `start`/`stop` commands, so exposing it to the LAN
+    /// by default would let any other host on the network control the bot.
+    /// A user who wants remote access can still set this to `"0.0.0.0:9013"`.
+    pub remote_control_bind: String,
+    /// Whether `net::raw_control::RawControlServer` should be started.
+    pub raw_control_enabled: String,
+    /// Bind address for the raw-control server, e.g. `"127.0.0.1:9014"` -
+    /// loopback-only by default for the same reason as `remote_control_bind`.
+    pub raw_control_bind: String,
+    /// Bite detection mode for `utils::audio_cue::BiteDetectionMode::from_setting`:
+    /// `"image"`, `"audio"`, or `"both"`.
+    pub bite_detection_mode: String,
+    /// Path (relative to the data dir) to the recorded bite-sound clip
+    /// `utils::audio_cue::BiteListener::start` compares the live capture
+    /// against.
+    pub bite_audio_reference_clip: String,
+    /// Match threshold passed to `BiteListener::start` - lower is stricter.
+    pub bite_audio_threshold: String,
+    /// Which `capture_backend::CaptureBackend` `ScreenService::new` picks:
+    /// `"direct"` (default, OS framebuffer grab) or `"portal"` (XDG desktop
+    /// portal screencast, for Wayland compositors with no direct grab API).
+    pub capture_backend: String,
 }
 
 impl Default for Settings {
@@ -49,22 +84,68 @@ impl Default for Settings {
             bait_key: "N".to_string(),
             fish_key: "F".to_string(),
             esc_key: "ESC".to_string(),
+            remote_control_enabled: "false".to_string(),
+            remote_control_bind: "127.0.0.1:9013".to_string(),
+            raw_control_enabled: "false".to_string(),
+            raw_control_bind: "127.0.0.1:9014".to_string(),
+            bite_detection_mode: "image".to_string(),
+            bite_audio_reference_clip: "audio/bite.wav".to_string(),
+            bite_audio_threshold: "0.35".to_string(),
+            capture_backend: "direct".to_string(),
         }
     }
 }
 
-/// Get settings file path
+/// Prefix for environment variables that override a settings key, e.g.
+/// `BLUEMANCING_START_KEY` overrides `start_key`.
+const ENV_PREFIX: &str = "BLUEMANCING_";
+
+impl Settings {
+    /// Resolve settings by layering, in order: built-in defaults, the
+    /// settings file, then `BLUEMANCING_`-prefixed environment variables,
+    /// and deserialize the merged map into a typed `Settings`.
+    pub fn load() -> Settings {
+        let mut merged = get_settings();
+
+        for (key, value) in std::env::vars() {
+            if let Some(setting_key) = key.strip_prefix(ENV_PREFIX) {
+                merged.insert(setting_key.to_lowercase(), value);
+            }
+        }
+
+        let value = serde_json::Value::Object(
+            merged
+                .into_iter()
+                .map(|(k, v)| (k, serde_json::Value::String(v)))
+                .collect(),
+        );
+
+        serde_json::from_value(value).unwrap_or_default()
+    }
+}
+
+/// Get settings file path, preferring whichever supported extension
+/// actually exists on disk (checked in this order) and falling back to the
+/// JSON path as the default for a fresh install.
 fn get_settings_path() -> std::path::PathBuf {
-    get_data_dir().join("config").join("settings.json")
+    let config_dir = get_data_dir().join("config");
+    for ext in ["json", "toml", "yaml", "yml", "ron"] {
+        let candidate = config_dir.join("settings").with_extension(ext);
+        if candidate.exists() {
+            return candidate;
+        }
+    }
+    config_dir.join("settings.json")
 }
 
 /// Get current settings
 pub fn get_settings() -> HashMap<String, String> {
     let settings_file = get_settings_path();
-    
+
     if settings_file.exists() {
         if let Ok(content) = fs::read_to_string(&settings_file) {
-            if let Ok(user_settings) = serde_json::from_str::<HashMap<String, serde_json::Value>>(&content) {
+            let format = ConfigFormat::from_path(&settings_file);
+            if let Ok(user_settings) = format.parse::<HashMap<String, serde_json::Value>>(&content) {
                 let mut settings = DEFAULT_SETTINGS.clone();
                 for (key, value) in user_settings {
                     if let Some(s) = value.as_str() {
@@ -75,7 +156,7 @@ pub fn get_settings() -> HashMap<String, String> {
             }
         }
     }
-    
+
     DEFAULT_SETTINGS.clone()
 }
 
@@ -84,6 +165,293 @@ pub fn get_resolution_folder() -> String {
     get_settings().get("resolution").cloned().unwrap_or_else(|| "1920x1080".to_string())
 }
 
+/// Directory holding named settings profiles.
+fn profiles_dir() -> std::path::PathBuf {
+    get_data_dir().join("config").join("profiles")
+}
+
+/// Path to a named profile's settings file.
+fn profile_path(name: &str) -> std::path::PathBuf {
+    profiles_dir().join(format!("{}.json", name))
+}
+
+/// File tracking which profile is currently active.
+fn active_profile_path() -> std::path::PathBuf {
+    get_data_dir().join("config").join("active_profile")
+}
+
+/// List available profile names, sorted.
+pub fn list_profiles() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(profiles_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| e.path().file_stem().map(|s| s.to_string_lossy().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// The currently active profile name, or `None` if none has been set yet.
+pub fn active_profile() -> Option<String> {
+    fs::read_to_string(active_profile_path())
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Layer `profile`'s settings file (if present) over `DEFAULT_SETTINGS`.
+/// An unknown profile simply falls back to the defaults, same as
+/// `get_settings()` does for a missing `settings.json`.
+pub fn get_settings_for(profile: &str) -> HashMap<String, String> {
+    let path = profile_path(profile);
+    let mut settings = DEFAULT_SETTINGS.clone();
+
+    if let Ok(content) = fs::read_to_string(&path) {
+        let format = ConfigFormat::from_path(&path);
+        if let Ok(user_settings) = format.parse::<HashMap<String, serde_json::Value>>(&content) {
+            for (key, value) in user_settings {
+                if let Some(s) = value.as_str() {
+                    settings.insert(key, s.to_string());
+                }
+            }
+        }
+    }
+
+    settings
+}
+
+/// Make `name` the active profile, creating its settings file from
+/// `DEFAULT_SETTINGS` first if it doesn't exist yet.
+pub fn switch_profile(name: &str) -> std::io::Result<()> {
+    fs::create_dir_all(profiles_dir())?;
+
+    let path = profile_path(name);
+    if !path.exists() {
+        let defaults = serde_json::to_string_pretty(&*DEFAULT_SETTINGS).unwrap_or_default();
+        fs::write(&path, defaults)?;
+    }
+
+    fs::write(active_profile_path(), name)
+}
+
+/// Spawn a background thread that watches the settings file for writes and
+/// invokes `on_change` with the freshly re-resolved settings on every edit,
+/// so a running bot can pick up settings changes without a restart. This is
+/// opt-in: callers that never invoke `watch_settings` see no behavior change.
+pub fn watch_settings<F>(on_change: F) -> notify::Result<()>
+where
+    F: Fn(HashMap<String, String>) + Send + 'static,
+{
+    use notify::{Event, EventKind, RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+
+    let settings_file = get_settings_path();
+
+    let (tx, rx) = channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&settings_file, RecursiveMode::NonRecursive)?;
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for as long as this thread runs.
+        let _watcher = watcher;
+        for res in rx {
+            let event = match res {
+                Ok(event) => event,
+                Err(e) => {
+                    tracing::warn!("[SETTINGS] Watch error on {}: {:?}", settings_file.display(), e);
+                    continue;
+                }
+            };
+
+            if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                continue;
+            }
+
+            tracing::info!("[SETTINGS] Reloading settings from {}", settings_file.display());
+            on_change(get_settings());
+        }
+    });
+
+    Ok(())
+}
+
+/// A single step in a dotted settings path: either an object key or an
+/// array index written as `[N]`, e.g. `"keys.start"` or `"list[0].name"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Split a dotted path into its segments, mirroring the `config` crate's
+/// path syntax: `.` separates object keys and trailing `[N]` groups index
+/// into an array.
+fn parse_path(path: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+
+    for part in path.split('.') {
+        let mut rest = part;
+        if let Some(bracket_pos) = rest.find('[') {
+            let key = &rest[..bracket_pos];
+            if !key.is_empty() {
+                segments.push(PathSegment::Key(key.to_string()));
+            }
+            rest = &rest[bracket_pos..];
+            while let Some(start) = rest.find('[') {
+                match rest[start..].find(']') {
+                    Some(end) => {
+                        if let Ok(idx) = rest[start + 1..start + end].parse::<usize>() {
+                            segments.push(PathSegment::Index(idx));
+                        }
+                        rest = &rest[start + end + 1..];
+                    }
+                    None => break,
+                }
+            }
+        } else if !rest.is_empty() {
+            segments.push(PathSegment::Key(rest.to_string()));
+        }
+    }
+
+    segments
+}
+
+/// Recursively merge `overlay` into `base`, in place: an object's keys are
+/// merged key-by-key, anything else in `overlay` replaces the value at that
+/// spot in `base` outright.
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match overlay {
+        serde_json::Value::Object(overlay_map) => {
+            if !base.is_object() {
+                *base = serde_json::Value::Object(serde_json::Map::new());
+            }
+            let base_map = base.as_object_mut().unwrap();
+            for (key, value) in overlay_map {
+                merge_json(base_map.entry(key).or_insert(serde_json::Value::Null), value);
+            }
+        }
+        other => *base = other,
+    }
+}
+
+/// The current settings, as a `serde_json::Value` object, for path-based
+/// reads: `DEFAULT_SETTINGS`' flat keys, overlaid with whatever is actually
+/// on disk in the settings file - parsed as real, possibly-nested JSON, the
+/// same shape `set_path` writes, so a `set_path`/`get_path` round-trip works
+/// for nested paths rather than just top-level keys.
+fn settings_value() -> serde_json::Value {
+    let mut root = serde_json::Value::Object(
+        DEFAULT_SETTINGS
+            .iter()
+            .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
+            .collect(),
+    );
+
+    let settings_file = get_settings_path();
+    if let Some(content) = settings_file
+        .exists()
+        .then(|| fs::read_to_string(&settings_file).ok())
+        .flatten()
+    {
+        if let Ok(file_value) = ConfigFormat::from_path(&settings_file).parse(&content) {
+            merge_json(&mut root, file_value);
+        }
+    }
+
+    root
+}
+
+/// Read a dotted-path value out of the current settings, e.g.
+/// `get_path("keys.start")`. Returns `None` if any segment along the path
+/// is missing.
+pub fn get_path(path: &str) -> Option<serde_json::Value> {
+    let root = settings_value();
+    let mut current = &root;
+
+    for segment in parse_path(path) {
+        current = match segment {
+            PathSegment::Key(key) => current.get(&key)?,
+            PathSegment::Index(index) => current.get(index)?,
+        };
+    }
+
+    Some(current.clone())
+}
+
+/// Write a dotted-path value into the settings file, creating any missing
+/// objects/arrays along the way, and persist the result to disk. Existing
+/// settings are preserved; only the addressed path is overwritten.
+pub fn set_path(path: &str, value: serde_json::Value) -> std::io::Result<()> {
+    let settings_file = get_settings_path();
+
+    let mut root: serde_json::Value = settings_file
+        .exists()
+        .then(|| fs::read_to_string(&settings_file).ok())
+        .flatten()
+        .and_then(|content| ConfigFormat::from_path(&settings_file).parse(&content).ok())
+        .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+
+    let segments = parse_path(path);
+    let Some((last, ancestors)) = segments.split_last() else {
+        return Ok(());
+    };
+
+    let mut current = &mut root;
+    for segment in ancestors {
+        current = match segment {
+            PathSegment::Key(key) => {
+                if !current.is_object() {
+                    *current = serde_json::Value::Object(serde_json::Map::new());
+                }
+                current
+                    .as_object_mut()
+                    .unwrap()
+                    .entry(key.clone())
+                    .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+            }
+            PathSegment::Index(index) => {
+                if !current.is_array() {
+                    *current = serde_json::Value::Array(Vec::new());
+                }
+                let arr = current.as_array_mut().unwrap();
+                while arr.len() <= *index {
+                    arr.push(serde_json::Value::Null);
+                }
+                &mut arr[*index]
+            }
+        };
+    }
+
+    match last {
+        PathSegment::Key(key) => {
+            if !current.is_object() {
+                *current = serde_json::Value::Object(serde_json::Map::new());
+            }
+            current.as_object_mut().unwrap().insert(key.clone(), value);
+        }
+        PathSegment::Index(index) => {
+            if !current.is_array() {
+                *current = serde_json::Value::Array(Vec::new());
+            }
+            let arr = current.as_array_mut().unwrap();
+            while arr.len() <= *index {
+                arr.push(serde_json::Value::Null);
+            }
+            arr[*index] = value;
+        }
+    }
+
+    if let Some(parent) = settings_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let serialized = serde_json::to_string_pretty(&root)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    fs::write(&settings_file, serialized)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +468,45 @@ mod tests {
         let folder = get_resolution_folder();
         assert!(!folder.is_empty());
     }
+
+    #[test]
+    fn test_settings_load_applies_env_override() {
+        std::env::set_var("BLUEMANCING_START_KEY", "F1");
+        let settings = Settings::load();
+        std::env::remove_var("BLUEMANCING_START_KEY");
+        assert_eq!(settings.start_key, "F1");
+    }
+
+    #[test]
+    fn test_get_settings_for_unknown_profile_falls_back_to_defaults() {
+        let settings = get_settings_for("definitely_not_a_real_profile_xyz");
+        assert_eq!(settings.get("resolution"), DEFAULT_SETTINGS.get("resolution"));
+    }
+
+    #[test]
+    fn test_parse_path_splits_keys_and_indices() {
+        assert_eq!(
+            parse_path("keys.start"),
+            vec![PathSegment::Key("keys".to_string()), PathSegment::Key("start".to_string())]
+        );
+        assert_eq!(
+            parse_path("list[2].name"),
+            vec![
+                PathSegment::Key("list".to_string()),
+                PathSegment::Index(2),
+                PathSegment::Key("name".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_path_reads_flat_settings_key() {
+        assert_eq!(
+            get_path("start_key"),
+            Some(serde_json::Value::String(
+                DEFAULT_SETTINGS.get("start_key").unwrap().clone()
+            ))
+        );
+        assert_eq!(get_path("not.a.real.path"), None);
+    }
 }
@@ -0,0 +1,205 @@
+//! Debug-evidence capture: annotates a captured frame with the match rectangle
+//! and score, losslessly optimizes it, and writes it into `logs/evidence/`
+//! alongside the JSON session logs.
+
+#![allow(dead_code)]
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use image::GrayImage;
+use opencv::core::{Mat, MatTraitConst, Point, Rect, Scalar};
+use opencv::{imgproc, prelude::*};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use super::image_service::{crc32, ImageService};
+use crate::utils::path::get_data_dir;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Directory evidence PNGs are written to, alongside the JSON session logs.
+pub(crate) fn evidence_dir() -> PathBuf {
+    get_data_dir().join("logs").join("evidence")
+}
+
+/// Sanitize a timestamp for use in a filename (colons aren't valid in Windows paths).
+pub(crate) fn sanitize_timestamp(timestamp: &str) -> String {
+    timestamp.replace(':', "-")
+}
+
+fn paeth_predictor(a: u8, b: u8, c: u8) -> u8 {
+    let (a, b, c) = (a as i32, b as i32, c as i32);
+    let p = a + b - c;
+    let pa = (p - a).abs();
+    let pb = (p - b).abs();
+    let pc = (p - c).abs();
+    if pa <= pb && pa <= pc {
+        a as u8
+    } else if pb <= pc {
+        b as u8
+    } else {
+        c as u8
+    }
+}
+
+/// Apply one of the five standard PNG row filters (0=None, 1=Sub, 2=Up, 3=Average,
+/// 4=Paeth) uniformly across every scanline of an interleaved RGB buffer.
+fn filter_image(filter_type: u8, width: usize, height: usize, bpp: usize, rgb: &[u8]) -> Vec<u8> {
+    let stride = width * bpp;
+    let mut filtered = Vec::with_capacity((stride + 1) * height);
+    let mut prior = vec![0u8; stride];
+
+    for y in 0..height {
+        let row = &rgb[y * stride..(y + 1) * stride];
+        filtered.push(filter_type);
+        for x in 0..stride {
+            let a = if x >= bpp { row[x - bpp] } else { 0 };
+            let b = prior[x];
+            let c = if x >= bpp { prior[x - bpp] } else { 0 };
+            let value = match filter_type {
+                0 => row[x],
+                1 => row[x].wrapping_sub(a),
+                2 => row[x].wrapping_sub(b),
+                3 => row[x].wrapping_sub(((a as u16 + b as u16) / 2) as u8),
+                4 => row[x].wrapping_sub(paeth_predictor(a, b, c)),
+                _ => unreachable!("only filter types 0-4 are attempted"),
+            };
+            filtered.push(value);
+        }
+        prior = row.to_vec();
+    }
+
+    filtered
+}
+
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(data)
+        .expect("writing to an in-memory buffer cannot fail");
+    encoder
+        .finish()
+        .expect("writing to an in-memory buffer cannot fail")
+}
+
+fn write_chunk(buf: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    buf.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    buf.extend_from_slice(chunk_type);
+    buf.extend_from_slice(data);
+
+    let mut crc_input = Vec::with_capacity(4 + data.len());
+    crc_input.extend_from_slice(chunk_type);
+    crc_input.extend_from_slice(data);
+    buf.extend_from_slice(&crc32(&crc_input).to_be_bytes());
+}
+
+/// Encode an interleaved 8-bit RGB buffer as a PNG, trying every standard row
+/// filter and keeping whichever deflates smallest, so evidence images stay cheap
+/// to accumulate in `logs/evidence/`.
+pub(crate) fn encode_optimized_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let (w, h) = (width as usize, height as usize);
+
+    let best_idat = (0u8..=4)
+        .map(|filter_type| zlib_compress(&filter_image(filter_type, w, h, 3, rgb)))
+        .min_by_key(Vec::len)
+        .unwrap_or_default();
+
+    let mut png = Vec::new();
+    png.extend_from_slice(&PNG_SIGNATURE);
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.extend_from_slice(&[8, 2, 0, 0, 0]); // 8-bit depth, RGB truecolor, default compression/filter/interlace
+    write_chunk(&mut png, b"IHDR", &ihdr);
+    write_chunk(&mut png, b"IDAT", &best_idat);
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Draw the match rectangle and score onto a captured grayscale frame and encode
+/// the result as an optimized PNG. `rect` is `(x1, y1, x2, y2)` in the same
+/// coordinate space as `gray`.
+pub(crate) fn annotate_and_encode(
+    gray: &GrayImage,
+    rect: Option<(i32, i32, i32, i32)>,
+    label: &str,
+    score: f32,
+) -> Option<Vec<u8>> {
+    let (width, height) = (gray.width(), gray.height());
+
+    let gray_mat = ImageService::gray_image_to_mat(gray).ok()?;
+    let mut bgr = Mat::default();
+    imgproc::cvt_color(&gray_mat, &mut bgr, imgproc::COLOR_GRAY2BGR, 0).ok()?;
+
+    if let Some((x1, y1, x2, y2)) = rect {
+        let shape = Rect::new(x1, y1, (x2 - x1).max(1), (y2 - y1).max(1));
+        imgproc::rectangle(
+            &mut bgr,
+            shape,
+            Scalar::new(0.0, 0.0, 255.0, 0.0),
+            2,
+            imgproc::LINE_8,
+            0,
+        )
+        .ok()?;
+    }
+
+    imgproc::put_text(
+        &mut bgr,
+        &format!("{label} score={score:.3}"),
+        Point::new(4, 16),
+        imgproc::FONT_HERSHEY_SIMPLEX,
+        0.5,
+        Scalar::new(0.0, 255.0, 0.0, 0.0),
+        1,
+        imgproc::LINE_8,
+        false,
+    )
+    .ok()?;
+
+    let mut rgb = Mat::default();
+    imgproc::cvt_color(&bgr, &mut rgb, imgproc::COLOR_BGR2RGB, 0).ok()?;
+
+    let data = rgb.data_bytes().ok()?;
+    Some(encode_optimized_png(width, height, data))
+}
+
+/// Write an annotated evidence PNG, named after `timestamp` so it can be
+/// cross-referenced with the matching `CatchLogEntry`/`BrokenRodLogEntry`.
+pub(crate) fn write_evidence(png: &[u8], label: &str, timestamp: &str) -> Option<PathBuf> {
+    let dir = evidence_dir();
+    fs::create_dir_all(&dir).ok()?;
+
+    let path = dir.join(format!("{}_{}.png", sanitize_timestamp(timestamp), label));
+    fs::write(&path, png).ok()?;
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_timestamp_strips_colons() {
+        assert_eq!(
+            sanitize_timestamp("2026-07-26T10:00:00+00:00"),
+            "2026-07-26T10-00-00+00-00"
+        );
+    }
+
+    #[test]
+    fn test_filter_image_none_is_passthrough() {
+        let rgb = vec![10u8, 20, 30, 40, 50, 60];
+        let filtered = filter_image(0, 2, 1, 3, &rgb);
+        assert_eq!(filtered, vec![0, 10, 20, 30, 40, 50, 60]);
+    }
+
+    #[test]
+    fn test_encode_optimized_png_has_valid_signature() {
+        let rgb = vec![128u8; 4 * 4 * 3];
+        let png = encode_optimized_png(4, 4, &rgb);
+        assert_eq!(&png[0..8], &PNG_SIGNATURE);
+    }
+}
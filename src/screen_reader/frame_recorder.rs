@@ -0,0 +1,123 @@
+//! Bounded "black box" ring buffer of recently captured window frames.
+//!
+//! `handle_no_progress_loop` only ever sees the moment recovery starts, not
+//! what the screen looked like during the seconds leading up to it, so a
+//! stuck state reported after the fact is unreproducible. `FrameRecorder`
+//! retains the last `RING_CAPACITY` frames `ImageService` already captures
+//! for template matching - no extra screen grab - and `flush` dumps all of
+//! them as PNGs into a timestamped folder under `logs/stuck/`, alongside a
+//! `meta.json` recording why it fired and the detection thresholds in
+//! effect, turning a stuck report into a concrete folder a user can attach
+//! to a bug report.
+
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+
+use image::GrayImage;
+use parking_lot::Mutex;
+
+use super::debug_capture;
+use crate::utils::path::get_data_dir;
+
+/// Number of recent frames retained. At the ~50ms `CHECK_INTERVAL` cadence
+/// `main_loop` polls at, this covers roughly the last second of activity.
+const RING_CAPACITY: usize = 20;
+
+struct Frame {
+    timestamp: String,
+    image: GrayImage,
+}
+
+/// Ring buffer of recent captures, with a timestamped "flush to disk" dump
+/// for stuck states.
+pub struct FrameRecorder {
+    frames: Mutex<VecDeque<Frame>>,
+}
+
+impl FrameRecorder {
+    pub fn new() -> Self {
+        Self {
+            frames: Mutex::new(VecDeque::with_capacity(RING_CAPACITY)),
+        }
+    }
+
+    /// Retain `image`, captured at `timestamp`, dropping the oldest frame
+    /// once the ring is full.
+    pub fn push(&self, timestamp: &str, image: &GrayImage) {
+        let mut frames = self.frames.lock();
+        if frames.len() >= RING_CAPACITY {
+            frames.pop_front();
+        }
+        frames.push_back(Frame {
+            timestamp: timestamp.to_string(),
+            image: image.clone(),
+        });
+    }
+
+    /// Write every retained frame as a PNG into a new
+    /// `logs/stuck/<timestamp>/` folder, plus a `meta.json` recording
+    /// `reason` and `thresholds`, and return the folder path. A no-op
+    /// returning `None` if nothing's been captured yet.
+    pub fn flush(&self, reason: &str, thresholds: &serde_json::Value) -> Option<PathBuf> {
+        let frames = self.frames.lock();
+        if frames.is_empty() {
+            return None;
+        }
+
+        let dump_timestamp = chrono::Utc::now().to_rfc3339();
+        let dir = get_data_dir()
+            .join("logs")
+            .join("stuck")
+            .join(debug_capture::sanitize_timestamp(&dump_timestamp));
+        fs::create_dir_all(&dir).ok()?;
+
+        for (i, frame) in frames.iter().enumerate() {
+            let png_path = dir.join(format!(
+                "{:03}_{}.png",
+                i,
+                debug_capture::sanitize_timestamp(&frame.timestamp)
+            ));
+            let rgb: Vec<u8> = frame.image.pixels().flat_map(|p| [p[0], p[0], p[0]]).collect();
+            let png = debug_capture::encode_optimized_png(frame.image.width(), frame.image.height(), &rgb);
+            let _ = fs::write(&png_path, png);
+        }
+
+        let meta = serde_json::json!({
+            "reason": reason,
+            "dumped_at": dump_timestamp,
+            "frame_count": frames.len(),
+            "thresholds": thresholds,
+        });
+        if let Ok(json) = serde_json::to_string_pretty(&meta) {
+            let _ = fs::write(dir.join("meta.json"), json);
+        }
+
+        Some(dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_drops_oldest_frame_once_ring_is_full() {
+        let recorder = FrameRecorder::new();
+        let image = GrayImage::new(1, 1);
+        for i in 0..RING_CAPACITY + 5 {
+            recorder.push(&format!("t{}", i), &image);
+        }
+        let frames = recorder.frames.lock();
+        assert_eq!(frames.len(), RING_CAPACITY);
+        assert_eq!(frames.front().unwrap().timestamp, "t5");
+    }
+
+    #[test]
+    fn test_flush_with_no_frames_returns_none() {
+        let recorder = FrameRecorder::new();
+        assert!(recorder.flush("test", &serde_json::json!({})).is_none());
+    }
+}
@@ -3,17 +3,23 @@
 #![allow(dead_code)]
 
 use image::GrayImage;
+use once_cell::sync::Lazy;
 use opencv::{
     core::{min_max_loc, no_array, Mat, MatTraitConst, Point, Scalar, CV_32FC1, CV_8UC1},
     imgcodecs, imgproc,
     prelude::*,
 };
+use parking_lot::Mutex;
+use rayon::prelude::*;
 use rusty_tesseract::{Args, Image as TessImage};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use super::base::get_resolution_folder;
+use super::debug_capture;
+use super::frame_recorder::FrameRecorder;
 use super::screen_service::{Region, ScreenService};
 use crate::utils::path::get_data_dir;
 
@@ -22,11 +28,92 @@ use crate::utils::path::get_data_dir;
 /// and 0.8 represents a reasonable confidence for successful text detection.
 const DEFAULT_OCR_CONFIDENCE: f32 = 0.8;
 
+/// CRC32 lookup table (IEEE 802.3 polynomial, reflected), built once on first use.
+static CRC32_TABLE: Lazy<[u32; 256]> = Lazy::new(|| {
+    let mut table = [0u32; 256];
+    let mut n = 0u32;
+    while n < 256 {
+        let mut acc = n;
+        let mut i = 0;
+        while i < 8 {
+            acc = if acc & 1 == 1 {
+                0xEDB8_8320 ^ (acc >> 1)
+            } else {
+                acc >> 1
+            };
+            i += 1;
+        }
+        table[n as usize] = acc;
+        n += 1;
+    }
+    table
+});
+
+/// Compute the CRC32 checksum of a byte slice, used to detect template file edits.
+/// Also reused by the debug-evidence PNG writer, since PNG chunk checksums use the
+/// same IEEE 802.3 polynomial.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(0xFFFF_FFFFu32, |acc, &b| {
+        (acc >> 8) ^ CRC32_TABLE[((acc & 0xFF) ^ b as u32) as usize]
+    })
+}
+
+/// A decoded template, cached alongside the CRC32 of the source file so edits invalidate it.
+struct CachedTemplate {
+    crc: u32,
+    gray: Mat,
+    mask: Option<Mat>,
+}
+
+/// Tracker for the best-scoring template match found across parallel tasks.
+/// Score and name are held together behind one mutex so a winning `offer`
+/// commits both atomically - splitting them (e.g. a CAS'd score alongside a
+/// separately-locked name) lets two threads interleave and leave a higher
+/// score paired with the wrong name.
+struct BestMatchTracker {
+    best: Mutex<(f32, Option<String>)>,
+}
+
+impl BestMatchTracker {
+    fn new() -> Self {
+        Self {
+            best: Mutex::new((0.0, None)),
+        }
+    }
+
+    /// Offer a candidate match, updating the tracked best if `score` beats it.
+    fn offer(&self, name: &str, score: f32) {
+        let mut best = self.best.lock();
+        if score > best.0 {
+            *best = (score, Some(name.to_string()));
+        }
+    }
+
+    fn into_best(self) -> (Option<String>, f32) {
+        let (score, name) = self.best.into_inner();
+        (name, score)
+    }
+}
+
 /// Image service for template matching and fish detection
 pub struct ImageService {
     screen_service: ScreenService,
     target_images_folder: PathBuf,
     resolution_folder: String,
+    /// Decoded template cache, keyed by template path and validated by file CRC32.
+    template_cache: Mutex<HashMap<PathBuf, CachedTemplate>>,
+    /// Cap on rayon worker threads used for parallel template matching.
+    /// `None` uses rayon's global thread pool.
+    max_threads: Option<usize>,
+    /// When enabled, `find_image_in_window`/`find_minigame_arrow` write an annotated
+    /// evidence PNG to `logs/evidence/` for every match attempt.
+    debug_capture: bool,
+    /// Path of the most recently written evidence PNG, if any, so it can later be
+    /// renamed to line up with a `CatchLogEntry`/`BrokenRodLogEntry` timestamp.
+    last_evidence: Mutex<Option<PathBuf>>,
+    /// Rolling "black box" buffer of recently captured frames, flushed to
+    /// `logs/stuck/` on a no-progress timeout or a lost game window.
+    frame_recorder: FrameRecorder,
 }
 
 impl ImageService {
@@ -37,6 +124,169 @@ impl ImageService {
             screen_service: ScreenService::new(),
             target_images_folder: base.join("images"),
             resolution_folder: get_resolution_folder(),
+            template_cache: Mutex::new(HashMap::new()),
+            max_threads: None,
+            debug_capture: false,
+            last_evidence: Mutex::new(None),
+            frame_recorder: FrameRecorder::new(),
+        }
+    }
+
+    /// Cap the number of rayon worker threads used for parallel template matching.
+    /// Pass `None` to fall back to rayon's global thread pool.
+    pub fn set_max_threads(&mut self, max_threads: Option<usize>) {
+        self.max_threads = max_threads;
+    }
+
+    /// Enable or disable writing annotated evidence PNGs to `logs/evidence/` for
+    /// every match attempt made by `find_image_in_window`/`find_minigame_arrow`.
+    pub fn set_debug_capture(&mut self, enabled: bool) {
+        self.debug_capture = enabled;
+    }
+
+    /// Rename the most recently written evidence PNG so its filename carries
+    /// `timestamp`, letting it be cross-referenced with the session log entry
+    /// (`CatchLogEntry`/`BrokenRodLogEntry`) that shares that timestamp.
+    pub fn link_evidence_to_log(&self, timestamp: &str) -> Option<PathBuf> {
+        let mut last = self.last_evidence.lock();
+        let old_path = last.take()?;
+
+        let dir = old_path.parent()?.to_path_buf();
+        let label = old_path
+            .file_stem()?
+            .to_str()?
+            .splitn(2, '_')
+            .nth(1)?
+            .to_string();
+        let new_path = dir.join(format!(
+            "{}_{}.png",
+            debug_capture::sanitize_timestamp(timestamp),
+            label
+        ));
+
+        fs::rename(&old_path, &new_path).ok()?;
+        Some(new_path)
+    }
+
+    /// Annotate and save the current match attempt as an evidence PNG, recording
+    /// its path so a later call to `link_evidence_to_log` can rename it. No-op
+    /// unless `debug_capture` is enabled.
+    fn record_evidence(
+        &self,
+        gray: &GrayImage,
+        rect: Option<(i32, i32, i32, i32)>,
+        label: &str,
+        score: f32,
+    ) {
+        if !self.debug_capture {
+            return;
+        }
+
+        let png = match debug_capture::annotate_and_encode(gray, rect, label, score) {
+            Some(png) => png,
+            None => return,
+        };
+
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        if let Some(path) = debug_capture::write_evidence(&png, label, &timestamp) {
+            *self.last_evidence.lock() = Some(path);
+        }
+    }
+
+    /// Write `gray`, captured just now, into the rolling "black box" frame
+    /// buffer. Called unconditionally (unlike `record_evidence`) since
+    /// retaining a handful of recent frames in memory is cheap and doesn't
+    /// depend on `debug_capture` being enabled.
+    fn record_frame(&self, gray: &GrayImage) {
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        self.frame_recorder.push(&timestamp, gray);
+    }
+
+    /// Flush the rolling frame buffer to `logs/stuck/<timestamp>/`, tagging
+    /// the dump with `reason` (e.g. `"no_progress_timeout"`) and the
+    /// detection `thresholds` in effect, so a stuck state leaves behind a
+    /// concrete set of PNGs instead of only a log line. Returns the folder
+    /// path, or `None` if no frames had been captured yet.
+    pub fn dump_frame_ring(&self, reason: &str, thresholds: &serde_json::Value) -> Option<PathBuf> {
+        let dir = self.frame_recorder.flush(reason, thresholds)?;
+        tracing::info!("[IMAGE] Dumped frame ring to {:?} (reason: {})", dir, reason);
+        Some(dir)
+    }
+
+    /// Build a dedicated thread pool honoring `max_threads`, if one was set.
+    fn build_thread_pool(&self) -> Option<rayon::ThreadPool> {
+        let threads = self.max_threads?;
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .ok()
+    }
+
+    /// Load a template from `path`, reusing the cached decode when the file's CRC32
+    /// still matches what's stored. `loader` is only invoked on a cache miss.
+    fn cached_template(
+        &self,
+        path: &Path,
+        loader: impl FnOnce(&Path) -> Option<(Mat, Option<Mat>)>,
+    ) -> Option<(Mat, Option<Mat>)> {
+        let bytes = fs::read(path).ok()?;
+        let crc = crc32(&bytes);
+
+        if let Some(entry) = self.template_cache.lock().get(path) {
+            if entry.crc == crc {
+                return Some((entry.gray.clone(), entry.mask.clone()));
+            }
+        }
+
+        let (gray, mask) = loader(path)?;
+        self.template_cache.lock().insert(
+            path.to_path_buf(),
+            CachedTemplate {
+                crc,
+                gray: gray.clone(),
+                mask: mask.clone(),
+            },
+        );
+        Some((gray, mask))
+    }
+
+    /// Decode a template with alpha support and split it into a grayscale `Mat`
+    /// plus an optional mask derived from the alpha channel (alpha > 0).
+    fn load_and_extract(path: &Path) -> Option<(Mat, Option<Mat>)> {
+        let template_img = Self::load_template_unchanged(path).ok()?;
+        if template_img.empty() {
+            return None;
+        }
+
+        if template_img.channels() == 4 {
+            let mut channels = opencv::core::Vector::<Mat>::new();
+            opencv::core::split(&template_img, &mut channels).ok()?;
+            if channels.len() < 4 {
+                return None;
+            }
+
+            let ch0 = channels.get(0).ok()?;
+            let ch1 = channels.get(1).ok()?;
+            let ch2 = channels.get(2).ok()?;
+            let alpha = channels.get(3).ok()?;
+
+            let mut bgr = Mat::default();
+            let bgr_channels = opencv::core::Vector::<Mat>::from_iter([ch0, ch1, ch2]);
+            opencv::core::merge(&bgr_channels, &mut bgr).ok()?;
+
+            let mut gray = Mat::default();
+            imgproc::cvt_color(&bgr, &mut gray, imgproc::COLOR_BGR2GRAY, 0).ok()?;
+
+            let mut mask = Mat::default();
+            imgproc::threshold(&alpha, &mut mask, 1.0, 255.0, imgproc::THRESH_BINARY).ok()?;
+
+            Some((gray, Some(mask)))
+        } else if template_img.channels() == 3 {
+            let mut gray = Mat::default();
+            imgproc::cvt_color(&template_img, &mut gray, imgproc::COLOR_BGR2GRAY, 0).ok()?;
+            Some((gray, None))
+        } else {
+            Some((template_img, None))
         }
     }
 
@@ -46,7 +296,7 @@ impl ImageService {
     }
 
     /// Convert image::GrayImage to OpenCV Mat
-    fn gray_image_to_mat(img: &GrayImage) -> opencv::Result<Mat> {
+    pub(crate) fn gray_image_to_mat(img: &GrayImage) -> opencv::Result<Mat> {
         let (width, height) = (img.width() as i32, img.height() as i32);
         let data = img.as_raw();
 
@@ -115,16 +365,26 @@ impl ImageService {
         )?;
 
         let img_gray = screenshot.to_luma8();
+        self.record_frame(&img_gray);
 
         // Convert to OpenCV Mat
         let img_mat = Self::gray_image_to_mat(&img_gray).ok()?;
 
-        // Load template
-        let template = Self::load_template_grayscale(image_path).ok()?;
-        if template.empty() {
-            tracing::warn!("[IMAGE] Template not found or empty: {:?}", image_path);
-            return None;
-        }
+        // Load template (cached by file CRC32 so repeated lookups skip the decode)
+        let template = match self.cached_template(image_path, |p| {
+            let mat = Self::load_template_grayscale(p).ok()?;
+            if mat.empty() {
+                None
+            } else {
+                Some((mat, None))
+            }
+        }) {
+            Some((mat, _)) => mat,
+            None => {
+                tracing::warn!("[IMAGE] Template not found or empty: {:?}", image_path);
+                return None;
+            }
+        };
 
         // Skip if template is larger than image
         if template.cols() >= img_mat.cols() || template.rows() >= img_mat.rows() {
@@ -159,6 +419,13 @@ impl ImageService {
         )
         .ok()?;
 
+        let match_rect = (
+            max_loc.x,
+            max_loc.y,
+            max_loc.x + template.cols(),
+            max_loc.y + template.rows(),
+        );
+
         if max_val >= threshold as f64 {
             let click_x = x1 + max_loc.x + template.cols() / 2;
             let click_y = y1 + max_loc.y + template.rows() / 2;
@@ -170,6 +437,7 @@ impl ImageService {
                 max_val,
                 threshold
             );
+            self.record_evidence(&img_gray, Some(match_rect), image_name, max_val as f32);
             return Some((click_x, click_y));
         }
 
@@ -179,6 +447,7 @@ impl ImageService {
             max_val,
             threshold
         );
+        self.record_evidence(&img_gray, Some(match_rect), image_name, max_val as f32);
         None
     }
 
@@ -202,7 +471,30 @@ impl ImageService {
         let screenshot =
             self.screen_service
                 .safe_screenshot(capture_region, 3, Duration::from_millis(100))?;
-        Some(screenshot.to_luma8())
+        let gray = screenshot.to_luma8();
+        self.record_frame(&gray);
+        Some(gray)
+    }
+
+    /// Capture the window with frame-difference gating and publish the delta to
+    /// `SHARED_STATE` for the UI. Callers can skip expensive detection for this
+    /// tick when the returned `changed` is `false`.
+    pub fn capture_window_change_detection(
+        &self,
+        window_rect: Option<(i32, i32, i32, i32)>,
+    ) -> Option<super::screen_service::CaptureResult> {
+        let (x1, y1, x2, y2) = window_rect?;
+        let w = (x2 - x1).max(0) as u32;
+        let h = (y2 - y1).max(0) as u32;
+
+        let result = self.screen_service.capture_with_change_detection(
+            Some(Region::new(x1, y1, w, h)),
+            3,
+            Duration::from_millis(100),
+        )?;
+
+        crate::utils::bot_state::SHARED_STATE.set_frame_delta(result.delta);
+        Some(result)
     }
 
     /// Find best matching fish using OCR (like Python version)
@@ -343,96 +635,28 @@ impl ImageService {
         let arrow_folder = self.target_images_folder.join(&self.resolution_folder);
 
         let templates = ["left-high.png", "right-high.png"];
-        let mut best_match: Option<String> = None;
-        let mut best_score = 0.0f32;
-
-        for template_name in &templates {
-            let template_path = arrow_folder.join(template_name);
-            if !template_path.exists() {
-                continue;
-            }
-
-            // Load template with alpha channel support (like Python cv2.IMREAD_UNCHANGED)
-            let template_img = match Self::load_template_unchanged(&template_path) {
-                Ok(t) => t,
-                Err(_) => continue,
-            };
-
-            if template_img.empty() {
-                continue;
-            }
-
-            // Handle alpha channel if present (4-channel image)
-            let (template, mask): (Mat, Option<Mat>) = if template_img.channels() == 4 {
-                // Extract BGR and alpha channel
-                let mut channels = opencv::core::Vector::<Mat>::new();
-                if opencv::core::split(&template_img, &mut channels).is_err() {
-                    continue;
-                }
-
-                // Verify we have at least 4 channels
-                if channels.len() < 4 {
-                    continue;
-                }
+        let candidates: Vec<(&str, PathBuf)> = templates
+            .iter()
+            .map(|name| (*name, arrow_folder.join(name)))
+            .filter(|(_, path)| path.exists())
+            .collect();
 
-                // Convert BGR to grayscale
-                let mut bgr = Mat::default();
-                let mut gray = Mat::default();
-
-                // Merge BGR channels (first 3) - explicitly get each channel
-                let ch0 = match channels.get(0) {
-                    Ok(c) => c,
-                    Err(_) => continue,
-                };
-                let ch1 = match channels.get(1) {
-                    Ok(c) => c,
-                    Err(_) => continue,
-                };
-                let ch2 = match channels.get(2) {
-                    Ok(c) => c,
-                    Err(_) => continue,
-                };
-                let bgr_channels = opencv::core::Vector::<Mat>::from_iter([ch0, ch1, ch2]);
-
-                if opencv::core::merge(&bgr_channels, &mut bgr).is_err() {
-                    continue;
-                }
-
-                if imgproc::cvt_color(&bgr, &mut gray, imgproc::COLOR_BGR2GRAY, 0).is_err() {
-                    continue;
-                }
+        if candidates.is_empty() {
+            return (None, 0.0);
+        }
 
-                // Create mask from alpha channel (alpha > 0)
-                let alpha = match channels.get(3) {
-                    Ok(a) => a,
-                    Err(_) => continue,
-                };
-                let mut mask = Mat::default();
-                if imgproc::threshold(&alpha, &mut mask, 1.0, 255.0, imgproc::THRESH_BINARY)
-                    .is_err()
-                {
-                    continue;
-                }
+        let tracker = BestMatchTracker::new();
 
-                (gray, Some(mask))
-            } else {
-                // Convert to grayscale if not already
-                let mut gray = Mat::default();
-                if template_img.channels() == 3 {
-                    if imgproc::cvt_color(&template_img, &mut gray, imgproc::COLOR_BGR2GRAY, 0)
-                        .is_err()
-                    {
-                        continue;
-                    }
-                } else {
-                    gray = template_img;
-                }
-                (gray, None)
+        let match_one = |name: &str, path: &Path| {
+            // Load template with alpha channel support, cached by file CRC32
+            let (template, mask) = match self.cached_template(path, Self::load_and_extract) {
+                Some(t) => t,
+                None => return,
             };
 
             // Skip if template is larger than crop
             if template.cols() >= crop_mat.cols() || template.rows() >= crop_mat.rows() {
-                continue;
+                return;
             }
 
             // Perform template matching with optional mask
@@ -455,22 +679,37 @@ impl ImageService {
             };
 
             if match_result.is_err() {
-                continue;
+                return;
             }
 
             // Find maximum value
             let mut max_val = 0.0;
             if min_max_loc(&result, None, Some(&mut max_val), None, None, &no_array()).is_err() {
-                continue;
+                return;
             }
 
-            if max_val as f32 > best_score {
-                best_score = max_val as f32;
-                best_match = Some(template_name.replace(".png", ""));
-            }
+            tracker.offer(&name.replace(".png", ""), max_val as f32);
+        };
+
+        let run = || {
+            candidates
+                .par_iter()
+                .for_each(|(name, path)| match_one(name, path));
+        };
+
+        match self.build_thread_pool() {
+            Some(pool) => pool.install(run),
+            None => run(),
         }
 
-        (best_match, best_score)
+        let (best_name, best_score) = tracker.into_best();
+        self.record_evidence(
+            &img_crop,
+            None,
+            best_name.as_deref().unwrap_or("minigame_arrow"),
+            best_score,
+        );
+        (best_name, best_score)
     }
 
     /// Get path to a target image
@@ -492,12 +731,63 @@ mod tests {
     use super::*;
     use image::Luma;
 
+    #[test]
+    fn test_link_evidence_to_log_renames_with_timestamp() {
+        let service = ImageService::new();
+        let dir = std::env::temp_dir().join(format!(
+            "blue_mancing_evidence_test_{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::create_dir_all(&dir);
+        let original = dir.join("2026-01-01T00-00-00Z_continue.png");
+        fs::write(&original, b"fake png bytes").unwrap();
+        *service.last_evidence.lock() = Some(original.clone());
+
+        let linked = service
+            .link_evidence_to_log("2026-07-26T10:00:00+00:00")
+            .expect("rename should succeed");
+
+        assert_eq!(
+            linked.file_name().unwrap().to_str().unwrap(),
+            "2026-07-26T10-00-00+00-00_continue.png"
+        );
+        assert!(!original.exists());
+        assert!(linked.exists());
+
+        let _ = fs::remove_file(&linked);
+        let _ = fs::remove_dir(&dir);
+    }
+
     #[test]
     fn test_image_service_new() {
         let service = ImageService::new();
         assert!(!service.resolution_folder.is_empty());
     }
 
+    #[test]
+    fn test_best_match_tracker_keeps_highest_score() {
+        let tracker = BestMatchTracker::new();
+        tracker.offer("left-high", 0.4);
+        tracker.offer("right-high", 0.9);
+        tracker.offer("left-high", 0.5);
+
+        let (name, score) = tracker.into_best();
+        assert_eq!(name, Some("right-high".to_string()));
+        assert_eq!(score, 0.9);
+    }
+
+    #[test]
+    fn test_crc32_known_vector() {
+        // "123456789" is the standard CRC-32/ISO-HDLC test vector.
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_crc32_changes_with_content() {
+        assert_ne!(crc32(b"template-a"), crc32(b"template-b"));
+    }
+
     #[test]
     fn test_get_image_path() {
         let service = ImageService::new();
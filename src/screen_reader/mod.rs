@@ -3,9 +3,16 @@
 #![allow(unused_imports)]
 
 pub mod base;
+pub mod capture_backend;
+mod debug_capture;
+mod frame_recorder;
 pub mod image_service;
 pub mod screen_service;
 
-pub use base::{get_resolution_folder, get_settings, Settings, DEFAULT_SETTINGS};
+pub use base::{
+    active_profile, get_path, get_resolution_folder, get_settings, get_settings_for,
+    list_profiles, set_path, switch_profile, watch_settings, Settings, DEFAULT_SETTINGS,
+};
+pub use capture_backend::CaptureBackend;
 pub use image_service::ImageService;
-pub use screen_service::ScreenService;
+pub use screen_service::{CaptureResult, MonitorInfo, ScreenService};
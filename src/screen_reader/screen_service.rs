@@ -5,11 +5,24 @@
 use std::time::Duration;
 use std::thread;
 use image::DynamicImage;
-use screenshots::Screen;
-use anyhow::{Result, Context};
+use anyhow::Result;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use super::base::Settings;
+use super::capture_backend::{self, CaptureBackend};
+use crate::utils::bot_state::SHARED_STATE;
+
+/// Side length of the luminance grid used for frame-difference gating.
+const CHANGE_GRID: usize = 16;
+const CHANGE_GRID_CELLS: usize = CHANGE_GRID * CHANGE_GRID;
+
+/// Default frame-delta threshold above which a region is considered "changed".
+/// Tuned against the 0..=(255 * 256) range `sum_abs_diff` can produce.
+const DEFAULT_CHANGE_THRESHOLD: u32 = 500;
 
 /// Region for screenshot capture
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Region {
     pub left: i32,
     pub top: i32,
@@ -33,27 +46,86 @@ impl Region {
     }
 }
 
+/// A connected display's id and bounds in global desktop coordinates, as
+/// reported by the OS.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MonitorInfo {
+    pub id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Result of a change-detection capture: the captured frame plus whether it
+/// differs enough from the previous frame of the same region to be worth
+/// running expensive detection on.
+#[derive(Debug, Clone)]
+pub struct CaptureResult {
+    pub image: DynamicImage,
+    pub changed: bool,
+    pub delta: u32,
+}
+
 /// Service for capturing screenshots
 pub struct ScreenService {
     region: Option<Region>,
+    /// Explicit monitor override (index into `list_monitors`). `None` auto-selects
+    /// based on `SharedBotState`'s tracked game window rect, falling back to the
+    /// primary screen.
+    monitor: Option<usize>,
+    /// Delta above which `capture_with_change_detection` reports `changed = true`.
+    change_threshold: u32,
+    /// Downsampled luminance grid of the last captured frame, alongside the
+    /// region it was captured from (reset whenever the region changes).
+    last_frame: Mutex<Option<(Region, [u8; CHANGE_GRID_CELLS])>>,
+    /// Where the actual pixels come from - a direct OS framebuffer grab by
+    /// default, or an XDG portal/PipeWire screencast on Wayland. Picked once,
+    /// from `base::Settings::capture_backend`, at construction time.
+    backend: Box<dyn CaptureBackend>,
 }
 
 impl ScreenService {
     /// Create a new screen service
     pub fn new() -> Self {
-        Self { region: None }
+        let backend_name = Settings::load().capture_backend;
+        Self {
+            region: None,
+            monitor: None,
+            change_threshold: DEFAULT_CHANGE_THRESHOLD,
+            last_frame: Mutex::new(None),
+            backend: capture_backend::build_backend(&backend_name),
+        }
     }
-    
+
     /// Create a new screen service with a specific region
     pub fn with_region(region: Region) -> Self {
-        Self { region: Some(region) }
+        let mut service = Self::new();
+        service.region = Some(region);
+        service
     }
-    
+
     /// Set the capture region
     pub fn set_region(&mut self, region: Option<Region>) {
         self.region = region;
     }
-    
+
+    /// Configure the frame-delta threshold used by `capture_with_change_detection`.
+    pub fn set_change_threshold(&mut self, threshold: u32) {
+        self.change_threshold = threshold;
+    }
+
+    /// Explicitly select which monitor to capture from. Pass `None` to go back
+    /// to auto-selecting based on the tracked game window rect.
+    pub fn set_monitor(&mut self, monitor: Option<usize>) {
+        self.monitor = monitor;
+    }
+
+    /// List connected monitors, in the same order `capture` indexes them.
+    pub fn list_monitors(&self) -> Result<Vec<MonitorInfo>> {
+        self.backend.list_monitors()
+    }
+
     /// Take a screenshot safely with retries
     pub fn safe_screenshot(&self, region: Option<Region>, retries: u32, delay: Duration) -> Option<DynamicImage> {
         for i in 0..retries {
@@ -72,33 +144,78 @@ impl ScreenService {
     pub fn screenshot(&self) -> Result<DynamicImage> {
         self.capture(self.region)
     }
+
+    /// Capture `region` with frame-difference gating: the new frame is
+    /// downsampled to a small luminance grid and compared against the previously
+    /// captured frame for the same region. `changed` is true only once the
+    /// summed absolute difference exceeds `change_threshold`.
+    ///
+    /// The very first capture of a region always reports `changed = true` (there
+    /// is nothing to compare against yet), and the stored frame is reset whenever
+    /// `region` differs from the last call.
+    pub fn capture_with_change_detection(
+        &self,
+        region: Option<Region>,
+        retries: u32,
+        delay: Duration,
+    ) -> Option<CaptureResult> {
+        let effective_region = region.or(self.region);
+        let image = self.safe_screenshot(region, retries, delay)?;
+        let grid = downsample_luminance(&image);
+
+        let mut last = self.last_frame.lock();
+        let (changed, delta) = match (*last, effective_region) {
+            (Some((prev_region, prev_grid)), Some(r)) if prev_region == r => {
+                let delta = sum_abs_diff(&prev_grid, &grid);
+                (delta > self.change_threshold, delta)
+            }
+            _ => (true, 0),
+        };
+
+        if let Some(r) = effective_region {
+            *last = Some((r, grid));
+        }
+
+        Some(CaptureResult { image, changed, delta })
+    }
     
     /// Internal capture method
     fn capture(&self, region: Option<Region>) -> Result<DynamicImage> {
-        let screens = Screen::all().context("Failed to get screens")?;
-        
-        if screens.is_empty() {
+        let monitors = self.backend.list_monitors()?;
+
+        if monitors.is_empty() {
             anyhow::bail!("No screens found");
         }
-        
-        // Get primary screen (first one)
-        let screen = &screens[0];
-        
-        let image = if let Some(r) = region {
-            screen.capture_area(r.left, r.top, r.width, r.height)
-                .context("Failed to capture area")?
+
+        let monitor_idx = self.resolve_screen_index(&monitors);
+        let monitor = &monitors[monitor_idx];
+
+        if let Some(r) = region {
+            // `Region` coordinates are in global desktop space; translate them into
+            // the chosen monitor's local space before capturing.
+            let local_left = r.left - monitor.x;
+            let local_top = r.top - monitor.y;
+            self.backend
+                .capture_area(monitor, local_left, local_top, r.width, r.height)
         } else {
-            screen.capture().context("Failed to capture screen")?
-        };
-        
-        // Convert to image::DynamicImage
-        let rgba_image = image::RgbaImage::from_raw(
-            image.width(),
-            image.height(),
-            image.to_vec(),
-        ).context("Failed to create image from raw data")?;
-        
-        Ok(DynamicImage::ImageRgba8(rgba_image))
+            self.backend.capture_monitor(monitor)
+        }
+    }
+
+    /// Pick which monitor to capture from: the explicit `monitor` override if set
+    /// and valid, otherwise the monitor whose bounds contain `SharedBotState`'s
+    /// tracked game window rect, otherwise the primary monitor.
+    fn resolve_screen_index(&self, monitors: &[MonitorInfo]) -> usize {
+        if let Some(idx) = self.monitor {
+            if idx < monitors.len() {
+                return idx;
+            }
+        }
+
+        SHARED_STATE
+            .get_game_window_rect()
+            .map(|rect| screen_index_for_rect(monitors, rect))
+            .unwrap_or(0)
     }
     
     /// Capture a specific region within a window rect
@@ -122,6 +239,52 @@ impl ScreenService {
     }
 }
 
+/// Downsample an image to a `CHANGE_GRID x CHANGE_GRID` grid of average
+/// luminance, for cheap frame-difference comparisons.
+fn downsample_luminance(img: &DynamicImage) -> [u8; CHANGE_GRID_CELLS] {
+    let gray = img.to_luma8();
+    let (w, h) = (gray.width().max(1) as u64, gray.height().max(1) as u64);
+
+    let mut sums = [0u64; CHANGE_GRID_CELLS];
+    let mut counts = [0u64; CHANGE_GRID_CELLS];
+
+    for (x, y, pixel) in gray.enumerate_pixels() {
+        let gx = ((x as u64 * CHANGE_GRID as u64) / w).min(CHANGE_GRID as u64 - 1) as usize;
+        let gy = ((y as u64 * CHANGE_GRID as u64) / h).min(CHANGE_GRID as u64 - 1) as usize;
+        let idx = gy * CHANGE_GRID + gx;
+        sums[idx] += pixel.0[0] as u64;
+        counts[idx] += 1;
+    }
+
+    let mut grid = [0u8; CHANGE_GRID_CELLS];
+    for i in 0..CHANGE_GRID_CELLS {
+        if counts[i] > 0 {
+            grid[i] = (sums[i] / counts[i]) as u8;
+        }
+    }
+    grid
+}
+
+/// Sum of absolute per-cell differences between two luminance grids.
+fn sum_abs_diff(a: &[u8; CHANGE_GRID_CELLS], b: &[u8; CHANGE_GRID_CELLS]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (*x as i32 - *y as i32).unsigned_abs())
+        .sum()
+}
+
+/// Find the monitor whose bounds contain `rect`'s top-left corner. Falls
+/// back to the primary monitor (index 0) if none contains it.
+fn screen_index_for_rect(monitors: &[MonitorInfo], rect: (i32, i32, i32, i32)) -> usize {
+    let (x1, y1, _, _) = rect;
+    monitors
+        .iter()
+        .position(|m| {
+            x1 >= m.x && y1 >= m.y && x1 < m.x + m.width as i32 && y1 < m.y + m.height as i32
+        })
+        .unwrap_or(0)
+}
+
 impl Default for ScreenService {
     fn default() -> Self {
         Self::new()
@@ -145,5 +308,34 @@ mod tests {
     fn test_screen_service_new() {
         let service = ScreenService::new();
         assert!(service.region.is_none());
+        assert!(service.monitor.is_none());
+    }
+
+    #[test]
+    fn test_set_monitor() {
+        let mut service = ScreenService::new();
+        service.set_monitor(Some(1));
+        assert_eq!(service.monitor, Some(1));
+    }
+
+    #[test]
+    fn test_downsample_luminance_uniform_image() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(32, 32, image::Rgb([100, 100, 100])));
+        let grid = downsample_luminance(&img);
+        assert!(grid.iter().all(|&v| (v as i32 - 100).abs() <= 1));
+    }
+
+    #[test]
+    fn test_sum_abs_diff_identical_grids_is_zero() {
+        let grid = [42u8; CHANGE_GRID_CELLS];
+        assert_eq!(sum_abs_diff(&grid, &grid), 0);
+    }
+
+    #[test]
+    fn test_sum_abs_diff_detects_change() {
+        let mut a = [0u8; CHANGE_GRID_CELLS];
+        let b = [0u8; CHANGE_GRID_CELLS];
+        a[0] = 10;
+        assert_eq!(sum_abs_diff(&a, &b), 10);
     }
 }
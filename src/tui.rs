@@ -0,0 +1,244 @@
+//! Optional terminal dashboard, an alternative to the webview overlay for
+//! headless/SSH or minimal-dependency runs. Enabled with the `tui` feature
+//! and selected at runtime with `--ui tui`; drives the same `SHARED_STATE`
+//! the webview overlay does, through the same `OverviewApi` methods, so
+//! neither frontend needs its own notion of "what the bot is doing".
+//!
+//! Modeled as a small component loop: `key_to_action` turns a `crossterm`
+//! key event into an `Action`, `App::apply` reduces that action against
+//! `OverviewApi`, and `draw` polls `is_running`/`get_activity`/`get_start_key`/`get_stop_key`
+//! (plus `SHARED_STATE`'s severity-tagged message log) on a ~100ms tick to
+//! render a status panel, the current start/stop keybinds, and a scrolling
+//! message pane.
+
+#![cfg(feature = "tui")]
+#![allow(dead_code)]
+
+use std::io;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::{Frame, Terminal};
+
+use crate::ui::OverviewApi;
+use crate::utils::bot_state::{MessageLevel, SHARED_STATE};
+
+/// Redraw/input-poll cadence - the same ~100ms the rest of the bot's
+/// status-polling loops use.
+const TICK: Duration = Duration::from_millis(100);
+
+/// A user-driven action, reduced against `OverviewApi` by `App::apply`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Start,
+    Stop,
+    RebindStart,
+    RebindStop,
+    Quit,
+}
+
+/// Whether the next keypress should rebind a hotkey instead of being mapped
+/// to an `Action`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RebindMode {
+    None,
+    AwaitingStart,
+    AwaitingStop,
+}
+
+/// TUI-local state layered on top of `OverviewApi`/`SHARED_STATE`.
+struct App {
+    overview: OverviewApi,
+    rebind: RebindMode,
+    /// Transient status line shown in the footer after a rebind attempt,
+    /// replacing the key-hint text until the next one.
+    status_line: String,
+    should_quit: bool,
+}
+
+impl App {
+    fn new() -> Self {
+        Self {
+            overview: OverviewApi::new(),
+            rebind: RebindMode::None,
+            status_line: String::new(),
+            should_quit: false,
+        }
+    }
+
+    /// Apply a reduced `Action` to `OverviewApi`/`SHARED_STATE`.
+    fn apply(&mut self, action: Action) {
+        match action {
+            Action::Start => self.overview.start_bot(),
+            Action::Stop => self.overview.stop_bot(),
+            Action::RebindStart => self.rebind = RebindMode::AwaitingStart,
+            Action::RebindStop => self.rebind = RebindMode::AwaitingStop,
+            Action::Quit => self.should_quit = true,
+        }
+    }
+
+    /// Consume a raw key code: if a rebind is pending, resolve it against
+    /// `OverviewApi::set_start_key`/`set_stop_key`; otherwise map it to an
+    /// `Action` and apply it.
+    fn handle_key(&mut self, code: KeyCode) {
+        match self.rebind {
+            RebindMode::AwaitingStart => return self.resolve_rebind(code, true),
+            RebindMode::AwaitingStop => return self.resolve_rebind(code, false),
+            RebindMode::None => {}
+        }
+
+        if let Some(action) = key_to_action(code) {
+            self.apply(action);
+        }
+    }
+
+    fn resolve_rebind(&mut self, code: KeyCode, is_start: bool) {
+        self.rebind = RebindMode::None;
+        let Some(key_str) = key_code_to_str(code) else {
+            self.status_line = "Rebind cancelled - unsupported key".to_string();
+            return;
+        };
+
+        let result = if is_start {
+            self.overview.set_start_key(&key_str)
+        } else {
+            self.overview.set_stop_key(&key_str)
+        };
+
+        self.status_line = match result {
+            Ok(bound) => format!(
+                "Bound {} key to {}",
+                if is_start { "start" } else { "stop" },
+                bound
+            ),
+            Err(e) => format!("Rebind failed: {}", e),
+        };
+    }
+}
+
+/// Map a raw key code to an `Action`. Returns `None` for keys with no
+/// binding, so `App::handle_key` can ignore them.
+fn key_to_action(code: KeyCode) -> Option<Action> {
+    match code {
+        KeyCode::Char('s') => Some(Action::Start),
+        KeyCode::Char('x') => Some(Action::Stop),
+        KeyCode::Char('r') => Some(Action::RebindStart),
+        KeyCode::Char('t') => Some(Action::RebindStop),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+        _ => None,
+    }
+}
+
+/// Translate a `crossterm` key code into the string format
+/// `utils::keybinds::resolve_key` expects, e.g. `KeyCode::F(9)` -> `"F9"`,
+/// `KeyCode::Char('m')` -> `"M"`.
+fn key_code_to_str(code: KeyCode) -> Option<String> {
+    match code {
+        KeyCode::F(n) => Some(format!("F{}", n)),
+        KeyCode::Char(c) => Some(c.to_ascii_uppercase().to_string()),
+        KeyCode::Esc => Some("ESC".to_string()),
+        _ => None,
+    }
+}
+
+/// Run the terminal dashboard until the user quits. Sets up and tears down
+/// raw mode/the alternate screen itself, mirroring `ui::start_ui`'s "blocks
+/// until closed" contract.
+pub fn run_tui() -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = App::new();
+    let result = run_app(&mut terminal, &mut app);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key.code);
+                }
+            }
+        }
+
+        if app.should_quit {
+            return Ok(());
+        }
+    }
+}
+
+/// Render the status panel, current keybinds, and the scrolling
+/// severity-tagged message log from `SHARED_STATE`.
+fn draw(frame: &mut Frame, app: &App) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    let running = app.overview.is_running();
+    let status = Paragraph::new(format!(
+        "{}  -  {}",
+        if running { "RUNNING" } else { "STOPPED" },
+        app.overview.get_activity(),
+    ))
+    .block(Block::default().borders(Borders::ALL).title("Status"));
+    frame.render_widget(status, layout[0]);
+
+    let keybinds = Paragraph::new(format!(
+        "start: {}  (r to rebind)   stop: {}  (t to rebind)",
+        app.overview.get_start_key(),
+        app.overview.get_stop_key(),
+    ))
+    .block(Block::default().borders(Borders::ALL).title("Keybinds"));
+    frame.render_widget(keybinds, layout[1]);
+
+    let messages: Vec<ListItem> = SHARED_STATE
+        .get_messages()
+        .iter()
+        .rev()
+        .map(|message| {
+            let color = match message.level {
+                MessageLevel::Info => Color::Gray,
+                MessageLevel::Warning => Color::Yellow,
+                MessageLevel::Error => Color::Red,
+            };
+            ListItem::new(Line::from(Span::styled(
+                message.text.clone(),
+                Style::default().fg(color),
+            )))
+        })
+        .collect();
+    let log = List::new(messages).block(Block::default().borders(Borders::ALL).title("Log"));
+    frame.render_widget(log, layout[2]);
+
+    let footer_text = if app.status_line.is_empty() {
+        "s: start  x: stop  r/t: rebind  q: quit".to_string()
+    } else {
+        app.status_line.clone()
+    };
+    frame.render_widget(Paragraph::new(footer_text), layout[3]);
+}
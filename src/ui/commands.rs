@@ -0,0 +1,489 @@
+//! Typed command registry for the dashboard's IPC bridge.
+//!
+//! `handle_dashboard_ipc` used to be one large string match where each arm
+//! hand-parsed its own params out of the raw JSON and hand-serialized its
+//! own response. Each dashboard action is now a small function registered
+//! here under its name; adding a setting means writing one function and
+//! registering it, not editing that match. `inject_api_bridge`'s JS method
+//! list is generated from the same registry (see `specs`), so the two can't
+//! drift out of sync.
+
+#![allow(dead_code)]
+
+use std::fs;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use pulldown_cmark::{html, Options, Parser};
+use serde_json::{json, Value};
+
+use crate::ui::ipc_guard::ParsedMessage;
+use crate::ui::overview_api::OverviewApi;
+use crate::ui::stats_api::StatsApi;
+use crate::utils::bot_state::SHARED_STATE;
+use crate::utils::control::{self, ThreadControlEvent};
+use crate::utils::keybinds::{self, get_key, Action};
+use crate::utils::path::get_data_dir;
+use crate::utils::updater;
+
+/// The JSON value to send back to JS, or an error message to wrap as
+/// `{"error": ...}`.
+pub type CommandResult = Result<Value, String>;
+type CommandFn = fn(&ParsedMessage) -> CommandResult;
+
+/// Single process-wide `StatsApi`, so `FishStats`'s mtime/TTL cache actually
+/// has something to cache across dashboard IPC calls - a fresh `StatsApi`
+/// per call would reload and re-summarize the logs on every single action.
+static STATS_API: Lazy<Mutex<StatsApi>> = Lazy::new(|| Mutex::new(StatsApi::new()));
+
+/// One registered dashboard action: its handler, and the parameter names
+/// `inject_api_bridge` should generate a JS wrapper function for.
+struct CommandSpec {
+    name: &'static str,
+    params: &'static [&'static str],
+    handler: CommandFn,
+}
+
+/// All dashboard actions, keyed by name. `capture_key_for` and the
+/// dialog-backed actions (`export_config`, `import_config`,
+/// `set_guide_path`) are listed here so they're part of the allowlist and
+/// the generated JS bridge, but are intercepted in `start_ui`'s
+/// `with_ipc_handler` before dispatch ever runs - key capture because it
+/// blocks on a keypress, the dialog actions because `wry`/`tao` require
+/// native file dialogs to run on the event loop's own thread. Their handlers
+/// below only cover the case where that interception is somehow bypassed.
+static COMMANDS: Lazy<Vec<CommandSpec>> = Lazy::new(|| {
+    vec![
+        CommandSpec { name: "get_guide", params: &[], handler: cmd_get_guide },
+        CommandSpec { name: "get_daily_table", params: &[], handler: cmd_get_daily_table },
+        CommandSpec { name: "get_overall_summary", params: &[], handler: cmd_get_overall_summary },
+        CommandSpec { name: "force_refresh_stats", params: &[], handler: cmd_force_refresh_stats },
+        CommandSpec { name: "get_sessions", params: &[], handler: cmd_get_sessions },
+        CommandSpec { name: "get_resolution", params: &[], handler: cmd_get_resolution },
+        CommandSpec { name: "set_resolution", params: &["value"], handler: cmd_set_resolution },
+        CommandSpec { name: "get_key", params: &["name"], handler: cmd_get_key },
+        CommandSpec { name: "get_bindings", params: &[], handler: cmd_get_bindings },
+        CommandSpec { name: "set_binding", params: &["action", "value"], handler: cmd_set_binding },
+        CommandSpec { name: "test_input", params: &["action"], handler: cmd_test_input },
+        CommandSpec { name: "capture_key_for", params: &["name"], handler: cmd_capture_key_for_unreachable },
+        CommandSpec { name: "export_config", params: &[], handler: cmd_dialog_unreachable },
+        CommandSpec { name: "import_config", params: &[], handler: cmd_dialog_unreachable },
+        CommandSpec { name: "set_guide_path", params: &[], handler: cmd_dialog_unreachable },
+        CommandSpec { name: "set_debug_overlay", params: &["value"], handler: cmd_set_debug_overlay },
+        CommandSpec { name: "get_debug_overlay", params: &[], handler: cmd_get_debug_overlay },
+        CommandSpec { name: "set_overlay_on_top", params: &["value"], handler: cmd_set_overlay_on_top },
+        CommandSpec { name: "get_overlay_on_top", params: &[], handler: cmd_get_overlay_on_top },
+        CommandSpec { name: "set_show_overlay", params: &["value"], handler: cmd_set_show_overlay },
+        CommandSpec { name: "get_show_overlay", params: &[], handler: cmd_get_show_overlay },
+        CommandSpec { name: "set_auto_bait", params: &["value"], handler: cmd_set_auto_bait },
+        CommandSpec { name: "get_auto_bait", params: &[], handler: cmd_get_auto_bait },
+        CommandSpec { name: "set_auto_rod", params: &["value"], handler: cmd_set_auto_rod },
+        CommandSpec { name: "get_auto_rod", params: &[], handler: cmd_get_auto_rod },
+        CommandSpec { name: "set_theme", params: &["value"], handler: cmd_set_theme },
+        CommandSpec { name: "get_theme", params: &[], handler: cmd_get_theme },
+        CommandSpec { name: "set_notifications", params: &["value"], handler: cmd_set_notifications },
+        CommandSpec { name: "get_notifications", params: &[], handler: cmd_get_notifications },
+        CommandSpec { name: "get_session_history", params: &[], handler: cmd_get_session_history },
+        CommandSpec { name: "set_webhook_url", params: &["value"], handler: cmd_set_webhook_url },
+        CommandSpec { name: "get_webhook_url", params: &[], handler: cmd_get_webhook_url },
+        CommandSpec { name: "test_webhook", params: &["value"], handler: cmd_test_webhook },
+        CommandSpec { name: "check_update", params: &[], handler: cmd_check_update },
+        CommandSpec { name: "set_arrow_threshold", params: &["value"], handler: cmd_set_arrow_threshold },
+        CommandSpec { name: "set_spam_cps", params: &["value"], handler: cmd_set_spam_cps },
+        CommandSpec { name: "set_no_progress_limit", params: &["value"], handler: cmd_set_no_progress_limit },
+        CommandSpec { name: "reset_stats", params: &[], handler: cmd_reset_stats },
+        CommandSpec { name: "rebind_keys", params: &[], handler: cmd_rebind_keys },
+        CommandSpec { name: "toggle_profiling", params: &["value"], handler: cmd_toggle_profiling },
+        CommandSpec { name: "toggle_pause", params: &[], handler: cmd_toggle_pause },
+        CommandSpec { name: "force_recovery", params: &[], handler: cmd_force_recovery },
+        CommandSpec { name: "set_module_enabled", params: &["name", "value"], handler: cmd_set_module_enabled },
+        CommandSpec { name: "set_fish_keep_policy", params: &["value"], handler: cmd_set_fish_keep_policy },
+        CommandSpec { name: "get_fish_keep_policy", params: &[], handler: cmd_get_fish_keep_policy },
+        CommandSpec { name: "set_notify_triggers", params: &["value"], handler: cmd_set_notify_triggers },
+        CommandSpec { name: "get_notify_triggers", params: &[], handler: cmd_get_notify_triggers },
+    ]
+});
+
+/// Look up and run the handler registered for `parsed.action`.
+pub fn dispatch(parsed: &ParsedMessage) -> CommandResult {
+    COMMANDS
+        .iter()
+        .find(|c| c.name == parsed.action)
+        .map(|c| (c.handler)(parsed))
+        .unwrap_or_else(|| Err(format!("Unknown action: {}", parsed.action)))
+}
+
+/// Every registered action name, for building the dashboard's IPC allowlist.
+pub fn action_names() -> Vec<&'static str> {
+    COMMANDS.iter().map(|c| c.name).collect()
+}
+
+/// `(name, params)` for every registered action, for generating
+/// `inject_api_bridge`'s JS wrapper methods.
+pub fn specs() -> impl Iterator<Item = (&'static str, &'static [&'static str])> {
+    COMMANDS.iter().map(|c| (c.name, c.params))
+}
+
+fn cmd_get_guide(_msg: &ParsedMessage) -> CommandResult {
+    let base = get_data_dir();
+    let guide_path = base.join("GUIDE.md");
+
+    let markdown = fs::read_to_string(&guide_path)
+        .or_else(|_| fs::read_to_string("GUIDE.md"))
+        .unwrap_or_else(|_| "# Guide\n\nGuide content not found.".to_string());
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    let parser = Parser::new_ext(&markdown, options);
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, parser);
+
+    Ok(Value::String(format!(
+        r#"<div class="intro-card">{}</div>"#,
+        html_output
+    )))
+}
+
+fn cmd_get_daily_table(_msg: &ParsedMessage) -> CommandResult {
+    let mut stats = STATS_API.lock();
+    Ok(Value::String(stats.get_daily_table()))
+}
+
+fn cmd_get_overall_summary(_msg: &ParsedMessage) -> CommandResult {
+    let mut stats = STATS_API.lock();
+    Ok(Value::String(stats.get_overall_summary()))
+}
+
+fn cmd_force_refresh_stats(_msg: &ParsedMessage) -> CommandResult {
+    STATS_API.lock().force_refresh();
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_sessions(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    serde_json::to_value(stats.get_sessions()).map_err(|e| e.to_string())
+}
+
+fn cmd_get_resolution(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::String(stats.get_resolution()))
+}
+
+fn cmd_set_resolution(msg: &ParsedMessage) -> CommandResult {
+    let res = msg.str("value").unwrap_or("1920x1080");
+    let mut stats = STATS_API.lock();
+    stats.set_resolution(res);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_key(msg: &ParsedMessage) -> CommandResult {
+    let name = msg.str("name").unwrap_or("");
+    Ok(Value::String(get_key(name).unwrap_or_default()))
+}
+
+/// Every action's bound key, e.g. `{"Start":"F9","Stop":"F10",...}`, for an
+/// editable keybind table (see `utils::keybinds::ACTION_BINDINGS`).
+fn cmd_get_bindings(_msg: &ParsedMessage) -> CommandResult {
+    let bindings: std::collections::HashMap<&str, String> = keybinds::all_bindings()
+        .into_iter()
+        .map(|(action, key)| (action.name(), key))
+        .collect();
+    serde_json::to_value(bindings).map_err(|e| e.to_string())
+}
+
+/// Rebind a named action (e.g. `"PauseResume"`) to a new key string.
+fn cmd_set_binding(msg: &ParsedMessage) -> CommandResult {
+    let action_name = msg.str("action").unwrap_or("");
+    let action = Action::from_name(action_name)
+        .ok_or_else(|| format!("Unknown action: {}", action_name))?;
+    let value = msg.str("value").unwrap_or("");
+    let resolved = keybinds::set_binding(action, value)?;
+    Ok(json!({ "success": true, "key": resolved }))
+}
+
+fn cmd_capture_key_for_unreachable(_msg: &ParsedMessage) -> CommandResult {
+    Err("capture_key_for must go through the async IPC path".to_string())
+}
+
+/// Fire a single configured input action (a key name from
+/// `utils::keybinds::DEFAULT_KEYS`, or `"click"` for the center of the
+/// tracked game window) so the dashboard can offer a "test input" button
+/// users hit before starting a long session.
+fn cmd_test_input(msg: &ParsedMessage) -> CommandResult {
+    let action = msg.str("action").unwrap_or("");
+    let result = OverviewApi::new().test_input(action)?;
+    Ok(json!({ "success": true, "message": result }))
+}
+
+fn cmd_dialog_unreachable(msg: &ParsedMessage) -> CommandResult {
+    Err(format!(
+        "{} must go through the main-thread dialog path",
+        msg.action
+    ))
+}
+
+fn cmd_set_debug_overlay(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
+    let mut stats = STATS_API.lock();
+    stats.set_show_debug_overlay(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_debug_overlay(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_show_debug_overlay()))
+}
+
+fn cmd_set_overlay_on_top(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
+    let mut stats = STATS_API.lock();
+    stats.set_overlay_always_on_top(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_overlay_on_top(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_overlay_always_on_top()))
+}
+
+fn cmd_set_show_overlay(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
+    let mut stats = STATS_API.lock();
+    stats.set_show_overlay(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_show_overlay(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_show_overlay()))
+}
+
+fn cmd_set_auto_bait(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(false);
+    let mut stats = STATS_API.lock();
+    stats.set_auto_bait(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_auto_bait(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_auto_bait()))
+}
+
+fn cmd_set_auto_rod(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(false);
+    let mut stats = STATS_API.lock();
+    stats.set_auto_rod(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_auto_rod(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_auto_rod()))
+}
+
+fn cmd_set_theme(msg: &ParsedMessage) -> CommandResult {
+    let theme = msg.str("value").unwrap_or("dark");
+    let mut stats = STATS_API.lock();
+    stats.set_theme(theme);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_theme(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::String(stats.get_theme()))
+}
+
+fn cmd_set_notifications(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
+    let mut stats = STATS_API.lock();
+    stats.set_notifications(value);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_notifications(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::Bool(stats.get_notifications()))
+}
+
+fn cmd_get_session_history(_msg: &ParsedMessage) -> CommandResult {
+    serde_json::to_value(crate::utils::bot_state::load_session_history()).map_err(|e| e.to_string())
+}
+
+fn cmd_set_webhook_url(msg: &ParsedMessage) -> CommandResult {
+    let url = msg.str("value").unwrap_or("");
+    let mut stats = STATS_API.lock();
+    stats.set_webhook_url(url);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_webhook_url(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::String(stats.get_webhook_url()))
+}
+
+fn cmd_test_webhook(msg: &ParsedMessage) -> CommandResult {
+    let url = msg.str("value").unwrap_or("");
+    if url.is_empty() {
+        return Err("No webhook URL provided".to_string());
+    }
+    Ok(Value::String(crate::utils::discord::test_webhook(url)))
+}
+
+/// Kick off a version check (and, if one is found, a download+install) on a
+/// background thread, pushing progress through `SHARED_STATE.set_update_status`
+/// so the overlay's `updateFromRust` handler can render it without the IPC
+/// call itself blocking on network I/O.
+fn cmd_check_update(_msg: &ParsedMessage) -> CommandResult {
+    if updater::DEV_MODE {
+        SHARED_STATE.set_update_status("uptodate", updater::APP_VERSION, 0.0);
+        return Ok(json!({ "success": true, "dev_mode": true }));
+    }
+
+    std::thread::spawn(|| {
+        SHARED_STATE.set_update_status("checking", updater::APP_VERSION, 0.0);
+        match updater::check_for_update_blocking() {
+            Some(info) => {
+                SHARED_STATE.set_update_status("downloading", &info.version, 0.0);
+                let version = info.version.clone();
+                match updater::download_and_install_blocking(&info, move |progress| {
+                    SHARED_STATE.set_update_status("downloading", &version, progress);
+                }) {
+                    Ok(()) => SHARED_STATE.set_update_status("available", &info.version, 100.0),
+                    Err(e) => {
+                        tracing::warn!("[UPDATE] Download/install failed: {}", e);
+                        SHARED_STATE.set_update_status("error", &info.version, 0.0);
+                    }
+                }
+            }
+            None => SHARED_STATE.set_update_status("uptodate", updater::APP_VERSION, 0.0),
+        }
+    });
+
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_set_arrow_threshold(msg: &ParsedMessage) -> CommandResult {
+    let value = msg
+        .get("value")
+        .and_then(|v| v.as_f64())
+        .ok_or("Missing numeric value")? as f32;
+    control::send(ThreadControlEvent::UpdateArrowThreshold(value));
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_set_spam_cps(msg: &ParsedMessage) -> CommandResult {
+    let value = msg
+        .get("value")
+        .and_then(|v| v.as_u64())
+        .ok_or("Missing numeric value")? as u32;
+    control::send(ThreadControlEvent::UpdateSpamCps(value));
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_set_no_progress_limit(msg: &ParsedMessage) -> CommandResult {
+    let value = msg
+        .get("value")
+        .and_then(|v| v.as_u64())
+        .ok_or("Missing numeric value")?;
+    control::send(ThreadControlEvent::UpdateNoProgressLimit(value));
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_reset_stats(_msg: &ParsedMessage) -> CommandResult {
+    control::send(ThreadControlEvent::ResetStats);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_rebind_keys(_msg: &ParsedMessage) -> CommandResult {
+    control::send(ThreadControlEvent::RebindKeys);
+    Ok(json!({ "success": true }))
+}
+
+/// Toggle per-detector timing in the macro loop, so a slow-running bot can be
+/// diagnosed without a debugger - see `MacroState::time_op`.
+fn cmd_toggle_profiling(msg: &ParsedMessage) -> CommandResult {
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(false);
+    control::send(ThreadControlEvent::ToggleProfiling(value));
+    Ok(json!({ "success": true }))
+}
+
+/// Toggle pause, the dashboard-side equivalent of the `pause_key` hotkey.
+fn cmd_toggle_pause(_msg: &ParsedMessage) -> CommandResult {
+    control::send(ThreadControlEvent::TogglePause);
+    Ok(json!({ "success": true }))
+}
+
+/// Trigger the recovery sequence on demand, the dashboard-side equivalent of
+/// the `recovery_key` hotkey.
+fn cmd_force_recovery(_msg: &ParsedMessage) -> CommandResult {
+    control::send(ThreadControlEvent::RequestForceRecovery);
+    Ok(json!({ "success": true }))
+}
+
+/// Enable or disable one automation module (e.g. `"AutoRecast"`) without
+/// restarting the bot - see `utils::bot_modules::ModuleRegistry`.
+fn cmd_set_module_enabled(msg: &ParsedMessage) -> CommandResult {
+    let name = msg.str("name").ok_or("Missing module name")?;
+    let value = msg.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
+    control::send(ThreadControlEvent::SetModuleEnabled(name.to_string(), value));
+    Ok(json!({ "success": true }))
+}
+
+/// Set the fish keep/release policy - `value` is a `fish::KeepPolicy`
+/// serialized as JSON (e.g. `{"type":"KeepAbove","value":15}`).
+fn cmd_set_fish_keep_policy(msg: &ParsedMessage) -> CommandResult {
+    let policy_json = msg.str("value").unwrap_or("");
+    serde_json::from_str::<crate::fish::KeepPolicy>(policy_json)
+        .map_err(|e| format!("Invalid keep policy: {}", e))?;
+    let mut stats = STATS_API.lock();
+    stats.set_fish_keep_policy(policy_json);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_fish_keep_policy(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::String(stats.get_fish_keep_policy()))
+}
+
+/// Set which `utils::notifications` triggers are enabled - `value` is a JSON
+/// object like `{"recovery_started": false}`.
+fn cmd_set_notify_triggers(msg: &ParsedMessage) -> CommandResult {
+    let triggers_json = msg.str("value").unwrap_or("{}");
+    serde_json::from_str::<std::collections::HashMap<String, bool>>(triggers_json)
+        .map_err(|e| format!("Invalid notify triggers: {}", e))?;
+    let mut stats = STATS_API.lock();
+    stats.set_notify_triggers(triggers_json);
+    Ok(json!({ "success": true }))
+}
+
+fn cmd_get_notify_triggers(_msg: &ParsedMessage) -> CommandResult {
+    let stats = STATS_API.lock();
+    Ok(Value::String(stats.get_notify_triggers()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(action: &str, body: &str) -> ParsedMessage {
+        crate::ui::ipc_guard::validate_ipc("about:blank", body, &[action])
+            .unwrap_or_else(|_| panic!("test message for {} should validate", action))
+    }
+
+    #[test]
+    fn test_unknown_action_is_an_error() {
+        let parsed = msg("frobnicate", r#"{"action":"frobnicate"}"#);
+        assert!(dispatch(&parsed).is_err());
+    }
+
+    #[test]
+    fn test_every_spec_name_is_dispatchable() {
+        for (name, _) in specs() {
+            if name == "capture_key_for" {
+                continue;
+            }
+            assert!(action_names().contains(&name));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! IPC hardening for the webview bridge.
+//!
+//! Both `with_ipc_handler` closures used to forward `request.body()`
+//! straight into `SHARED_STATE` mutation and filesystem reads with no
+//! checks beyond "is this valid JSON with an `action` field". Borrowing
+//! Tauri's "block remote URLs from accessing the IPC" model, every message
+//! is validated here before a handler acts on it: the page that sent it
+//! must be our own locally injected content (not a remote page that somehow
+//! ended up loaded in the webview), the message must be reasonably sized,
+//! and `action` must be on a known allowlist. `start`/`stop` are also
+//! rate-limited so a compromised or buggy page can't hammer the bot loop.
+
+#![allow(dead_code)]
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Maximum accepted IPC message length, in bytes. Generous for the largest
+/// legitimate payload (a settings path + value) with headroom, far below
+/// anything that could be used to waste memory.
+const MAX_MESSAGE_LEN: usize = 16 * 1024;
+
+/// Minimum time between accepted `start`/`stop` toggles.
+const TOGGLE_RATE_LIMIT: Duration = Duration::from_millis(500);
+
+/// A validated, parsed IPC message.
+#[derive(Debug, Clone)]
+pub struct ParsedMessage {
+    /// The request id echoed back through `window.__resolveApi`, or
+    /// `"null"` if the caller didn't send one.
+    pub id: String,
+    pub action: String,
+    value: serde_json::Value,
+}
+
+impl ParsedMessage {
+    /// Look up a field of the original message body by key.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.value.get(key)
+    }
+
+    /// Look up a string field of the original message body by key.
+    pub fn str(&self, key: &str) -> Option<&str> {
+        self.value.get(key).and_then(|v| v.as_str())
+    }
+}
+
+/// Why an IPC message was rejected before reaching a handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpcError {
+    /// The message didn't come from our own locally injected page content.
+    UntrustedOrigin(String),
+    /// The message body exceeded `MAX_MESSAGE_LEN`.
+    TooLarge(usize),
+    /// The body wasn't valid JSON.
+    InvalidJson,
+    /// The body was valid JSON but had no `action` string field.
+    MissingAction,
+    /// `action` wasn't on the caller-supplied allowlist.
+    UnknownAction(String),
+    /// A `start`/`stop` toggle arrived too soon after the last one.
+    RateLimited(String),
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpcError::UntrustedOrigin(origin) => write!(f, "untrusted IPC origin: {}", origin),
+            IpcError::TooLarge(len) => write!(f, "IPC message too large: {} bytes", len),
+            IpcError::InvalidJson => write!(f, "IPC message is not valid JSON"),
+            IpcError::MissingAction => write!(f, "IPC message is missing an \"action\" field"),
+            IpcError::UnknownAction(action) => write!(f, "unknown IPC action: {}", action),
+            IpcError::RateLimited(action) => write!(f, "IPC action rate-limited: {}", action),
+        }
+    }
+}
+
+/// Last time each rate-limited action was accepted.
+static LAST_TOGGLE: Lazy<Mutex<HashMap<String, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Is `origin` one of the locally injected content URLs wry uses for a page
+/// loaded via `with_html` (typically `about:blank`, sometimes a `data:`
+/// URL), or an empty origin? Anything else - a real `http(s)://` URL, a `file://` page that
+/// isn't ours - is rejected outright.
+fn is_trusted_origin(origin: &str) -> bool {
+    origin.is_empty() || origin == "about:blank" || origin.starts_with("data:")
+}
+
+/// Validate and parse a raw IPC message body. `origin` is the URL of the
+/// page that sent it (the webview's current document URL); `allowed_actions`
+/// is the handler-specific allowlist of actions it's willing to perform.
+pub fn validate_ipc(
+    origin: &str,
+    body: &str,
+    allowed_actions: &[&str],
+) -> Result<ParsedMessage, IpcError> {
+    if !is_trusted_origin(origin) {
+        return Err(IpcError::UntrustedOrigin(origin.to_string()));
+    }
+    if body.len() > MAX_MESSAGE_LEN {
+        return Err(IpcError::TooLarge(body.len()));
+    }
+
+    let value: serde_json::Value = serde_json::from_str(body).map_err(|_| IpcError::InvalidJson)?;
+    let action = value
+        .get("action")
+        .and_then(|a| a.as_str())
+        .ok_or(IpcError::MissingAction)?
+        .to_string();
+
+    if !allowed_actions.contains(&action.as_str()) {
+        return Err(IpcError::UnknownAction(action));
+    }
+
+    if action == "start" || action == "stop" {
+        let mut last_toggle = LAST_TOGGLE.lock();
+        let now = Instant::now();
+        if let Some(&previous) = last_toggle.get(&action) {
+            if now.duration_since(previous) < TOGGLE_RATE_LIMIT {
+                return Err(IpcError::RateLimited(action));
+            }
+        }
+        last_toggle.insert(action.clone(), now);
+    }
+
+    let id = value
+        .get("id")
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "null".to_string());
+
+    Ok(ParsedMessage { id, action, value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_untrusted_origin() {
+        let err = validate_ipc("https://evil.example", r#"{"action":"start"}"#, &["start"]);
+        assert_eq!(
+            err,
+            Err(IpcError::UntrustedOrigin("https://evil.example".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_action() {
+        let err = validate_ipc("about:blank", r#"{"action":"rm_rf"}"#, &["start", "stop"]);
+        assert_eq!(err, Err(IpcError::UnknownAction("rm_rf".to_string())));
+    }
+
+    #[test]
+    fn test_accepts_known_action_from_trusted_origin() {
+        let parsed = validate_ipc("about:blank", r#"{"action":"getStatus"}"#, &["getStatus"])
+            .expect("should validate");
+        assert_eq!(parsed.action, "getStatus");
+    }
+
+    #[test]
+    fn test_rejects_oversized_message() {
+        let huge = format!(r#"{{"action":"start","pad":"{}"}}"#, "x".repeat(MAX_MESSAGE_LEN));
+        let err = validate_ipc("about:blank", &huge, &["start"]);
+        assert!(matches!(err, Err(IpcError::TooLarge(_))));
+    }
+}
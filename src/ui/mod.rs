@@ -2,6 +2,8 @@
 
 #![allow(unused_imports)]
 
+mod commands;
+mod ipc_guard;
 pub mod overview_api;
 pub mod stats_api;
 pub mod ui_service;
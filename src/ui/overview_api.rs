@@ -2,44 +2,52 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+
+use crate::input::{EnigoInputBackend, InputBackend};
 use crate::utils::bot_state::{BotActivity, SHARED_STATE};
-use crate::utils::keybinds::{get_keys, key_to_str, resolve_key, set_keys};
+use crate::utils::keybinds::{self, get_key, key_to_str, Action};
 
 /// Overview API exposed to JavaScript
-pub struct OverviewApi {
-    start_key: String,
-    stop_key: String,
-}
+pub struct OverviewApi;
 
 impl OverviewApi {
     pub fn new() -> Self {
-        let (start, stop) = get_keys();
-        Self {
-            start_key: start,
-            stop_key: stop,
-        }
+        Self
     }
 
     pub fn get_start_key(&self) -> String {
-        key_to_str(&self.start_key)
+        key_to_str(&keybinds::get_binding(Action::Start))
     }
 
     pub fn get_stop_key(&self) -> String {
-        key_to_str(&self.stop_key)
+        key_to_str(&keybinds::get_binding(Action::Stop))
     }
 
     pub fn set_start_key(&mut self, key_str: &str) -> Result<String, String> {
-        let new_key = resolve_key(key_str).ok_or_else(|| format!("Invalid key: {}", key_str))?;
-        self.start_key = new_key.clone();
-        set_keys(&key_str, &self.get_stop_key())?;
-        Ok(new_key)
+        keybinds::set_binding(Action::Start, key_str)
     }
 
     pub fn set_stop_key(&mut self, key_str: &str) -> Result<String, String> {
-        let new_key = resolve_key(key_str).ok_or_else(|| format!("Invalid key: {}", key_str))?;
-        self.stop_key = new_key.clone();
-        set_keys(&self.get_start_key(), key_str)?;
-        Ok(new_key)
+        keybinds::set_binding(Action::Stop, key_str)
+    }
+
+    /// Get every action's bound key as JSON, e.g.
+    /// `{"Start":"F9","Stop":"F10","PauseResume":"F8",...}`, for the
+    /// overlay's editable keybind table.
+    pub fn get_bindings(&self) -> String {
+        let bindings: HashMap<&str, String> = keybinds::all_bindings()
+            .into_iter()
+            .map(|(action, key)| (action.name(), key))
+            .collect();
+        serde_json::to_string(&bindings).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Rebind a named action (e.g. `"PauseResume"`) to a new key string,
+    /// validated through `resolve_key`.
+    pub fn set_binding(&mut self, action: &str, key_str: &str) -> Result<String, String> {
+        let action = Action::from_name(action).ok_or_else(|| format!("Unknown action: {}", action))?;
+        keybinds::set_binding(action, key_str)
     }
 
     /// Start the fishing bot from UI
@@ -79,6 +87,36 @@ impl OverviewApi {
     pub fn get_detail(&self) -> String {
         SHARED_STATE.get_detail_message()
     }
+
+    /// Get the severity-tagged message log as JSON (`[{level, ts, text}, ...]`),
+    /// for the overlay's color-coded scrolling log.
+    pub fn get_messages(&self) -> String {
+        serde_json::to_string(&SHARED_STATE.get_messages()).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Fire a single configured input action against the tracked game window,
+    /// so the overlay can offer a "test input" button users hit before
+    /// starting a long session to confirm the bot can actually reach the game.
+    ///
+    /// `action` is either a configured key name from `utils::keybinds::DEFAULT_KEYS`
+    /// (e.g. `"fish_key"`), which gets tapped, or `"click"`, which clicks the
+    /// center of the tracked game window.
+    pub fn test_input(&self, action: &str) -> Result<String, String> {
+        let backend = EnigoInputBackend::new();
+
+        if action == "click" {
+            let (x1, y1, x2, y2) = SHARED_STATE
+                .get_game_window_rect()
+                .ok_or_else(|| "No game window is currently tracked".to_string())?;
+            let (cx, cy) = ((x1 + x2) / 2, (y1 + y2) / 2);
+            backend.mouse_click(cx, cy);
+            return Ok(format!("Clicked center of game window at ({}, {})", cx, cy));
+        }
+
+        let key = get_key(action).ok_or_else(|| format!("Unknown input action: {}", action))?;
+        backend.key_tap(&key);
+        Ok(format!("Tapped '{}' ({})", action, key))
+    }
 }
 
 impl Default for OverviewApi {
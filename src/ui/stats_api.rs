@@ -4,13 +4,134 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Timelike};
+use chrono::{DateTime, FixedOffset, Local, Timelike};
 
 use crate::utils::path::get_data_dir;
 use crate::utils::keybinds::get_key;
 use crate::fish::FishService;
 
+/// Source of "now" and the display timezone entries are bucketed into.
+/// Injected into `FishStats` so hour/day boundaries can be tested
+/// deterministically instead of depending on the system clock.
+pub trait Clock: Send + Sync {
+    /// Current time, already in the configured display offset.
+    fn now(&self) -> DateTime<FixedOffset>;
+    /// The display timezone offset entries are normalized into before bucketing.
+    fn offset(&self) -> FixedOffset;
+}
+
+/// Default `Clock` impl backed by the system clock and the machine's local
+/// UTC offset (captured once at construction).
+pub struct SystemClock {
+    offset: FixedOffset,
+}
+
+impl SystemClock {
+    pub fn new(offset: FixedOffset) -> Self {
+        Self { offset }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            offset: *Local::now().offset(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        chrono::Utc::now().with_timezone(&self.offset)
+    }
+
+    fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+/// Fixed `Clock` impl for tests: always returns the same instant.
+pub struct FixedClock {
+    now: DateTime<FixedOffset>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<FixedOffset>) -> Self {
+        Self { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        self.now
+    }
+
+    fn offset(&self) -> FixedOffset {
+        *self.now.offset()
+    }
+}
+
+/// How long a refresh stays valid before the source files are re-checked,
+/// even if their mtimes haven't changed. Bounds staleness when logs are
+/// written by another process without updating mtime-granularity timestamps.
+const CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Gap (in minutes) between consecutive catch timestamps that starts a new
+/// fishing session.
+const DEFAULT_SESSION_GAP_MINUTES: i64 = 5;
+
+/// Floor for a session's duration, in minutes, so a single-entry or
+/// zero-duration session still contributes a sane fish/min instead of
+/// dividing by zero. Approximates one cast-to-catch cycle.
+const MIN_SESSION_DURATION_MINUTES: f64 = 0.5;
+
+/// A contiguous block of fishing activity, split from the raw catch log
+/// whenever the gap between two entries exceeds `DEFAULT_SESSION_GAP_MINUTES`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FishSession {
+    pub start: String,
+    pub end: String,
+    pub catches: i32,
+    pub fails: i32,
+    pub xp: i32,
+    pub duration_minutes: f64,
+}
+
+/// Aggregate catch stats over a rolling window anchored to `Clock::now()`,
+/// rather than raw hourly map keys.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RollingWindowStats {
+    pub minutes: i64,
+    pub catches: i32,
+    pub fails: i32,
+    pub xp: i32,
+}
+
+impl RollingWindowStats {
+    pub fn catch_rate(&self) -> f64 {
+        let total = self.catches + self.fails;
+        if total > 0 {
+            (self.catches as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl FishSession {
+    /// Catches per minute of this session's active duration.
+    pub fn fish_per_min(&self) -> f64 {
+        if self.duration_minutes > 0.0 {
+            self.catches as f64 / self.duration_minutes
+        } else {
+            0.0
+        }
+    }
+}
+
 /// Log entry for a fishing catch
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FishLogEntry {
@@ -47,10 +168,23 @@ pub struct FishStats {
     fish_summary: HashMap<String, HashMap<String, HourlyStats>>,
     fish_types: Vec<String>,
     broken_summary: HashMap<String, HashMap<String, i32>>,
+    fishing_log_path: PathBuf,
+    broken_rods_path: PathBuf,
+    fishing_log_mtime: Option<SystemTime>,
+    broken_rods_mtime: Option<SystemTime>,
+    last_refresh: Option<Instant>,
+    clock: Box<dyn Clock>,
 }
 
 impl FishStats {
     pub fn new() -> Self {
+        Self::with_clock(Box::new(SystemClock::default()))
+    }
+
+    /// Create a `FishStats` driven by a specific `Clock`, e.g. a `FixedClock`
+    /// in tests that need deterministic hour/day boundaries.
+    pub fn with_clock(clock: Box<dyn Clock>) -> Self {
+        let base = get_data_dir();
         let mut stats = Self {
             fish_logs: Vec::new(),
             broken_logs: Vec::new(),
@@ -58,22 +192,61 @@ impl FishStats {
             fish_summary: HashMap::new(),
             fish_types: Vec::new(),
             broken_summary: HashMap::new(),
+            fishing_log_path: base.join("logs").join("fishing_log.json"),
+            broken_rods_path: base.join("logs").join("broken_rods.json"),
+            fishing_log_mtime: None,
+            broken_rods_mtime: None,
+            last_refresh: None,
+            clock,
         };
         stats.refresh();
         stats
     }
-    
+
+    /// Reload and re-summarize the source logs, but only if a source file's
+    /// mtime changed since the last refresh or the cache TTL has elapsed.
+    /// Call `force_refresh` to bypass this check.
     pub fn refresh(&mut self) {
-        let base = get_data_dir();
-        self.fish_logs = Self::load_json(&base.join("logs").join("fishing_log.json"));
-        self.broken_logs = Self::load_json(&base.join("logs").join("broken_rods.json"));
+        self.refresh_impl(false);
+    }
+
+    /// Unconditionally reload and re-summarize the source logs, bypassing the
+    /// mtime/TTL cache. Intended for a UI's manual "reload" action.
+    pub fn force_refresh(&mut self) {
+        self.refresh_impl(true);
+    }
+
+    fn refresh_impl(&mut self, force: bool) {
+        let fishing_mtime = Self::mtime(&self.fishing_log_path);
+        let broken_mtime = Self::mtime(&self.broken_rods_path);
+
+        let ttl_elapsed = self
+            .last_refresh
+            .map_or(true, |t| t.elapsed() >= CACHE_TTL);
+        let mtime_changed =
+            fishing_mtime != self.fishing_log_mtime || broken_mtime != self.broken_rods_mtime;
+
+        if !force && !ttl_elapsed && !mtime_changed {
+            return;
+        }
+
+        self.fish_logs = Self::load_json(&self.fishing_log_path);
+        self.broken_logs = Self::load_json(&self.broken_rods_path);
         self.fish_xp = self.get_fish_xp_map();
         let (summary, types) = self.summarize_fishing();
         self.fish_summary = summary;
         self.fish_types = types;
         self.broken_summary = self.summarize_broken_rods();
+
+        self.fishing_log_mtime = fishing_mtime;
+        self.broken_rods_mtime = broken_mtime;
+        self.last_refresh = Some(Instant::now());
     }
-    
+
+    fn mtime(path: &std::path::Path) -> Option<SystemTime> {
+        fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
     fn load_json<T: for<'de> Deserialize<'de> + Default>(path: &std::path::Path) -> Vec<T> {
         match fs::read_to_string(path) {
             Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
@@ -98,10 +271,10 @@ impl FishStats {
         
         for entry in &self.fish_logs {
             let dt = match DateTime::parse_from_rfc3339(&entry.timestamp) {
-                Ok(d) => d,
+                Ok(d) => d.with_timezone(&self.clock.offset()),
                 Err(_) => continue,
             };
-            
+
             let date_str = dt.format("%Y-%m-%d").to_string();
             let hour_str = format!("{:02}:00", dt.hour());
             
@@ -137,13 +310,13 @@ impl FishStats {
             }
             
             let dt = match DateTime::parse_from_rfc3339(&entry.timestamp) {
-                Ok(d) => d,
+                Ok(d) => d.with_timezone(&self.clock.offset()),
                 Err(_) => continue,
             };
-            
+
             let date_str = dt.format("%Y-%m-%d").to_string();
             let hour_str = format!("{:02}:00", dt.hour());
-            
+
             *summary.entry(date_str).or_default().entry(hour_str).or_insert(0) += 1;
         }
         
@@ -252,12 +425,15 @@ impl FishStats {
         let total_fish = total_caught + total_failed;
         let overall_rate = if total_fish > 0 { (total_caught as f64 / total_fish as f64) * 100.0 } else { 0.0 };
         
-        // Calculate average fish per minute based on total hours of data
-        // Each hour entry represents data from that hour block
-        let total_hours = self.fish_summary.values()
-            .map(|hours| hours.len())
-            .sum::<usize>() as f64;
-        let avg_fpm = if total_hours > 0.0 { total_caught as f64 / (total_hours * 60.0) } else { 0.0 };
+        // Average fish/min based on actual active session duration, rather
+        // than assuming every populated hour bucket is a full 60 minutes.
+        let sessions = self.analyze_sessions(DEFAULT_SESSION_GAP_MINUTES);
+        let total_session_minutes: f64 = sessions.iter().map(|s| s.duration_minutes).sum();
+        let avg_fpm = if total_session_minutes > 0.0 {
+            total_caught as f64 / total_session_minutes
+        } else {
+            0.0
+        };
         
         format!(r#"
         <h3 style='margin-bottom: 6px;'>Overall Stats</h3>
@@ -293,6 +469,138 @@ impl FishStats {
         dates.sort();
         dates
     }
+
+    /// Split the raw catch log into fishing sessions using the default gap
+    /// threshold, so the UI can render per-session throughput rows instead of
+    /// only hourly buckets.
+    pub fn get_sessions(&self) -> Vec<FishSession> {
+        self.analyze_sessions(DEFAULT_SESSION_GAP_MINUTES)
+    }
+
+    /// Total broken rods recorded across all days, for rule-engine telemetry.
+    pub fn get_broken_rod_count(&self) -> i32 {
+        self.broken_summary.values().flat_map(|h| h.values()).sum()
+    }
+
+    /// Catches/fails/xp from the last `minutes` minutes, anchored to
+    /// `clock.now()` rather than whichever hourly buckets happen to be
+    /// populated - so "last 15 minutes" means the same thing at any time of day.
+    pub fn get_rolling_window(&self, minutes: i64) -> RollingWindowStats {
+        let cutoff = self.clock.now() - chrono::Duration::minutes(minutes);
+        let mut result = RollingWindowStats {
+            minutes,
+            ..Default::default()
+        };
+
+        for entry in &self.fish_logs {
+            let dt = match DateTime::parse_from_rfc3339(&entry.timestamp) {
+                Ok(d) => d.with_timezone(&self.clock.offset()),
+                Err(_) => continue,
+            };
+            if dt < cutoff {
+                continue;
+            }
+
+            if entry.caught {
+                result.catches += 1;
+                let fish_type = entry
+                    .fish_type
+                    .clone()
+                    .unwrap_or_else(|| "undefined".to_string());
+                result.xp += self.fish_xp.get(&fish_type).copied().unwrap_or(1);
+            } else {
+                result.fails += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Daily table HTML for "today", as defined by the injected `Clock`,
+    /// rather than requiring the caller to know today's date string.
+    pub fn get_today_table(&mut self) -> String {
+        self.refresh();
+        let today = self.clock.now().format("%Y-%m-%d").to_string();
+        self.get_daily_table(&today)
+    }
+
+    /// Parse, sort, and split `fish_logs` into sessions, splitting whenever the
+    /// gap between consecutive entries exceeds `gap_minutes`.
+    fn analyze_sessions(&self, gap_minutes: i64) -> Vec<FishSession> {
+        let mut timestamped: Vec<(DateTime<FixedOffset>, &FishLogEntry)> = self
+            .fish_logs
+            .iter()
+            .filter_map(|entry| {
+                DateTime::parse_from_rfc3339(&entry.timestamp)
+                    .ok()
+                    .map(|dt| (dt.with_timezone(&self.clock.offset()), entry))
+            })
+            .collect();
+        timestamped.sort_by_key(|(dt, _)| *dt);
+
+        let gap = chrono::Duration::minutes(gap_minutes);
+        let mut sessions = Vec::new();
+        let mut current: Option<(DateTime<FixedOffset>, DateTime<FixedOffset>, i32, i32, i32)> =
+            None;
+
+        for (dt, entry) in timestamped {
+            let xp = if entry.caught {
+                let fish_type = entry
+                    .fish_type
+                    .clone()
+                    .unwrap_or_else(|| "undefined".to_string());
+                self.fish_xp.get(&fish_type).copied().unwrap_or(1)
+            } else {
+                0
+            };
+
+            match &mut current {
+                Some((_, end, catches, fails, xp_sum)) if dt - *end <= gap => {
+                    *end = dt;
+                    if entry.caught {
+                        *catches += 1;
+                        *xp_sum += xp;
+                    } else {
+                        *fails += 1;
+                    }
+                }
+                _ => {
+                    if let Some((start, end, catches, fails, xp_sum)) = current.take() {
+                        sessions.push(Self::build_session(start, end, catches, fails, xp_sum));
+                    }
+                    current = Some(if entry.caught {
+                        (dt, dt, 1, 0, xp)
+                    } else {
+                        (dt, dt, 0, 1, 0)
+                    });
+                }
+            }
+        }
+
+        if let Some((start, end, catches, fails, xp_sum)) = current {
+            sessions.push(Self::build_session(start, end, catches, fails, xp_sum));
+        }
+
+        sessions
+    }
+
+    fn build_session(
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+        catches: i32,
+        fails: i32,
+        xp: i32,
+    ) -> FishSession {
+        let raw_minutes = (end - start).num_seconds() as f64 / 60.0;
+        FishSession {
+            start: start.to_rfc3339(),
+            end: end.to_rfc3339(),
+            catches,
+            fails,
+            xp,
+            duration_minutes: raw_minutes.max(MIN_SESSION_DURATION_MINUTES),
+        }
+    }
 }
 
 impl Default for FishStats {
@@ -385,10 +693,94 @@ impl StatsApi {
             .map(|s| s == "true")
             .unwrap_or(true)
     }
-    
+
+    pub fn set_auto_bait(&mut self, value: bool) {
+        self.settings.insert("auto_bait".to_string(), value.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_auto_bait(&self) -> bool {
+        self.settings.get("auto_bait")
+            .map(|s| s == "true")
+            .unwrap_or(false)
+    }
+
+    pub fn set_auto_rod(&mut self, value: bool) {
+        self.settings.insert("auto_rod".to_string(), value.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_auto_rod(&self) -> bool {
+        self.settings.get("auto_rod")
+            .map(|s| s == "true")
+            .unwrap_or(false)
+    }
+
+    pub fn set_theme(&mut self, theme: &str) {
+        self.settings.insert("theme".to_string(), theme.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_theme(&self) -> String {
+        self.settings.get("theme").cloned().unwrap_or_else(|| "dark".to_string())
+    }
+
+    pub fn set_notifications(&mut self, value: bool) {
+        self.settings.insert("notifications".to_string(), value.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_notifications(&self) -> bool {
+        self.settings.get("notifications")
+            .map(|s| s == "true")
+            .unwrap_or(true)
+    }
+
+    pub fn set_webhook_url(&mut self, url: &str) {
+        self.settings.insert("discord_webhook_url".to_string(), url.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_webhook_url(&self) -> String {
+        self.settings.get("discord_webhook_url").cloned().unwrap_or_default()
+    }
+
+    /// Store the fish keep/release policy as its serialized JSON form - see
+    /// `fish::KeepPolicy` - so `FishService::should_keep` can read it back
+    /// without this module needing to know its shape.
+    pub fn set_fish_keep_policy(&mut self, policy_json: &str) {
+        self.settings.insert("fish_keep_policy".to_string(), policy_json.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_fish_keep_policy(&self) -> String {
+        self.settings
+            .get("fish_keep_policy")
+            .cloned()
+            .unwrap_or_else(|| serde_json::to_string(&crate::fish::KeepPolicy::KeepAll).unwrap_or_default())
+    }
+
+    /// Store which `utils::notifications` triggers are enabled, as a JSON
+    /// object mapping trigger key (e.g. `"broken_rod"`) to a bool. A trigger
+    /// missing from the map defaults to enabled.
+    pub fn set_notify_triggers(&mut self, triggers_json: &str) {
+        self.settings.insert("notify_triggers".to_string(), triggers_json.to_string());
+        self.save_settings();
+    }
+
+    pub fn get_notify_triggers(&self) -> String {
+        self.settings.get("notify_triggers").cloned().unwrap_or_else(|| "{}".to_string())
+    }
+
     pub fn get_daily_table(&mut self) -> String {
         self.stats.get_all_daily_tables()
     }
+
+    /// Force an immediate reload of the underlying logs, bypassing the
+    /// mtime/TTL cache. Intended for a manual "reload" button in the UI.
+    pub fn force_refresh(&mut self) {
+        self.stats.force_refresh();
+    }
     
     pub fn get_overall_summary(&mut self) -> String {
         self.stats.get_overall_summary()
@@ -397,6 +789,10 @@ impl StatsApi {
     pub fn get_dates(&self) -> Vec<String> {
         self.stats.get_dates()
     }
+
+    pub fn get_sessions(&self) -> Vec<FishSession> {
+        self.stats.get_sessions()
+    }
     
     pub fn get_key(&self, name: &str) -> Option<String> {
         get_key(name)
@@ -408,3 +804,87 @@ impl Default for StatsApi {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: &str, caught: bool) -> FishLogEntry {
+        FishLogEntry {
+            timestamp: timestamp.to_string(),
+            caught,
+            fish_type: None,
+        }
+    }
+
+    fn stats_with_logs(logs: Vec<FishLogEntry>) -> FishStats {
+        stats_with_logs_and_clock(logs, Box::new(SystemClock::default()))
+    }
+
+    fn stats_with_logs_and_clock(logs: Vec<FishLogEntry>, clock: Box<dyn Clock>) -> FishStats {
+        FishStats {
+            fish_logs: logs,
+            broken_logs: Vec::new(),
+            fish_xp: HashMap::new(),
+            fish_summary: HashMap::new(),
+            fish_types: Vec::new(),
+            broken_summary: HashMap::new(),
+            fishing_log_path: PathBuf::from("unused_fishing_log.json"),
+            broken_rods_path: PathBuf::from("unused_broken_rods.json"),
+            fishing_log_mtime: None,
+            broken_rods_mtime: None,
+            last_refresh: None,
+            clock,
+        }
+    }
+
+    #[test]
+    fn test_single_entry_session_uses_duration_floor() {
+        let stats = stats_with_logs(vec![entry("2026-01-01T10:00:00+00:00", true)]);
+        let sessions = stats.get_sessions();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].duration_minutes, MIN_SESSION_DURATION_MINUTES);
+        assert_eq!(sessions[0].catches, 1);
+    }
+
+    #[test]
+    fn test_gap_splits_into_separate_sessions() {
+        let stats = stats_with_logs(vec![
+            entry("2026-01-01T10:00:00+00:00", true),
+            entry("2026-01-01T10:02:00+00:00", true),
+            entry("2026-01-01T10:30:00+00:00", true),
+        ]);
+        let sessions = stats.get_sessions();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].catches, 2);
+        assert_eq!(sessions[1].catches, 1);
+    }
+
+    #[test]
+    fn test_rolling_window_excludes_entries_before_cutoff() {
+        let now: DateTime<FixedOffset> = DateTime::parse_from_rfc3339("2026-01-01T10:30:00+00:00")
+            .unwrap();
+        let stats = stats_with_logs_and_clock(
+            vec![
+                entry("2026-01-01T10:20:00+00:00", true), // within last 15 minutes
+                entry("2026-01-01T09:00:00+00:00", false), // too old
+            ],
+            Box::new(FixedClock::new(now)),
+        );
+
+        let window = stats.get_rolling_window(15);
+        assert_eq!(window.catches, 1);
+        assert_eq!(window.fails, 0);
+    }
+
+    #[test]
+    fn test_today_table_uses_injected_clock() {
+        let now: DateTime<FixedOffset> = DateTime::parse_from_rfc3339("2026-01-01T10:30:00+00:00")
+            .unwrap();
+        // `get_today_table` forces a fresh `refresh()` against (nonexistent)
+        // disk paths, so this only asserts the date it anchors to - not the
+        // log contents.
+        let mut stats = stats_with_logs_and_clock(Vec::new(), Box::new(FixedClock::new(now)));
+        assert_eq!(stats.get_today_table(), "<p>No data for 2026-01-01</p>");
+    }
+}
@@ -12,6 +12,8 @@ use std::fs;
 use crate::utils::path::get_data_dir;
 #[cfg(all(feature = "gui", windows))]
 use crate::utils::bot_state::{SHARED_STATE, BotActivity};
+#[cfg(all(feature = "gui", windows))]
+use crate::ui::stats_api::StatsApi;
 
 /// Window types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -44,17 +46,32 @@ pub fn register_window(window_type: Window, handle: WindowHandle) {
     }
 }
 
-/// Handle IPC message from JavaScript
+/// Actions the overlay's IPC handler is willing to perform.
+#[cfg(all(feature = "gui", windows))]
+const OVERLAY_ALLOWED_ACTIONS: &[&str] = &["start", "stop", "getStatus", "minimize", "close", "set_theme"];
+
+/// Handle IPC message from JavaScript. `origin` is the sending page's URL,
+/// checked against the overlay's own locally injected content so a remote
+/// or malicious page loaded into the webview can't drive the bot.
 #[cfg(all(feature = "gui", windows))]
-fn handle_ipc_message(message: &str) -> Option<String> {
+fn handle_ipc_message(origin: &str, message: &str) -> Option<String> {
+    use crate::ui::ipc_guard::{validate_ipc, IpcError};
     use crate::utils::bot_state::SHARED_STATE;
     use crate::utils::bot_state::BotActivity;
-    
-    // Parse the message as JSON
-    let parsed: serde_json::Value = serde_json::from_str(message).ok()?;
-    let action = parsed.get("action")?.as_str()?;
-    
-    match action {
+
+    let parsed = match validate_ipc(origin, message, OVERLAY_ALLOWED_ACTIONS) {
+        Ok(parsed) => parsed,
+        Err(IpcError::RateLimited(action)) => {
+            tracing::debug!("Overlay IPC: rate-limited '{}'", action);
+            return None;
+        }
+        Err(e) => {
+            tracing::warn!("Overlay IPC: rejected message: {}", e);
+            return None;
+        }
+    };
+
+    match parsed.action.as_str() {
         "start" => {
             println!("UI: Start button clicked");
             if !SHARED_STATE.is_running() {
@@ -82,135 +99,93 @@ fn handle_ipc_message(message: &str) -> Option<String> {
             // Window close is handled by the event loop
             Some(r#"{"success": true, "action": "close"}"#.to_string())
         }
-        _ => {
-            println!("UI: Unknown action: {}", action);
-            None
-        }
+        "set_theme" => crate::ui::commands::dispatch(&parsed).ok().map(|v| v.to_string()),
+        _ => unreachable!("validate_ipc only admits OVERLAY_ALLOWED_ACTIONS"),
     }
 }
 
-/// Handle IPC message from dashboard JavaScript - supports full API
+/// Actions the dashboard's IPC handler is willing to perform, generated from
+/// `commands::action_names()` so the allowlist and the registry can't drift
+/// apart. Includes `capture_key_for` even though it's intercepted before
+/// reaching `handle_dashboard_ipc` - it still has to clear the same
+/// allowlist/origin check.
 #[cfg(all(feature = "gui", windows))]
-fn handle_dashboard_ipc(message: &str) -> String {
-    use crate::utils::keybinds::get_key;
-    use crate::ui::stats_api::StatsApi;
-    use pulldown_cmark::{Parser, Options, html};
-    
-    // Parse the message as JSON
-    let parsed: serde_json::Value = match serde_json::from_str(message) {
-        Ok(v) => v,
-        Err(_) => return r#"{"error": "Invalid JSON"}"#.to_string(),
-    };
-    
-    let action = match parsed.get("action").and_then(|a| a.as_str()) {
-        Some(a) => a,
-        None => return r#"{"error": "Missing action"}"#.to_string(),
+static DASHBOARD_ALLOWED_ACTIONS: Lazy<Vec<&'static str>> =
+    Lazy::new(crate::ui::commands::action_names);
+
+/// Handle a dashboard IPC request and return `(id, result_json)`, where `id`
+/// is the request's `id` field echoed back verbatim (or `null` if the
+/// message didn't carry one) so the JS side can resolve the matching
+/// pending promise. `origin` is the sending page's URL, checked against the
+/// dashboard's own locally injected content. The action itself is looked up
+/// and run via `commands::dispatch`.
+#[cfg(all(feature = "gui", windows))]
+fn handle_dashboard_ipc(origin: &str, message: &str) -> (String, String) {
+    use crate::ui::commands;
+    use crate::ui::ipc_guard::{validate_ipc, IpcError};
+
+    // Best-effort id extraction for error responses: validation may reject a
+    // message before building a ParsedMessage, but the pending promise on
+    // the JS side still needs its id to resolve (rather than waiting out
+    // the full request timeout).
+    let fallback_id = || -> String {
+        serde_json::from_str::<serde_json::Value>(message)
+            .ok()
+            .and_then(|v| v.get("id").map(|v| v.to_string()))
+            .unwrap_or_else(|| "null".to_string())
     };
-    
-    match action {
-        "get_guide" => {
-            // Load and convert GUIDE.md to HTML
-            let base = get_data_dir();
-            let guide_path = base.join("GUIDE.md");
-            
-            let markdown = match fs::read_to_string(&guide_path) {
-                Ok(content) => content,
-                Err(_) => {
-                    // Try current directory as fallback
-                    match fs::read_to_string("GUIDE.md") {
-                        Ok(content) => content,
-                        Err(_) => "# Guide\n\nGuide content not found.".to_string(),
-                    }
-                }
-            };
-            
-            // Convert markdown to HTML
-            let mut options = Options::empty();
-            options.insert(Options::ENABLE_STRIKETHROUGH);
-            let parser = Parser::new_ext(&markdown, options);
-            let mut html_output = String::new();
-            html::push_html(&mut html_output, parser);
-            
-            // Wrap in a div with styling
-            let result = format!(r#"<div class="intro-card">{}</div>"#, html_output);
-            serde_json::to_string(&result).unwrap_or_else(|_| r#""""#.to_string())
-        }
-        "get_daily_table" => {
-            let mut stats = StatsApi::new();
-            let html = stats.get_daily_table();
-            serde_json::to_string(&html).unwrap_or_else(|_| r#""""#.to_string())
-        }
-        "get_overall_summary" => {
-            let mut stats = StatsApi::new();
-            let html = stats.get_overall_summary();
-            serde_json::to_string(&html).unwrap_or_else(|_| r#""""#.to_string())
-        }
-        "get_resolution" => {
-            let stats = StatsApi::new();
-            let res = stats.get_resolution();
-            serde_json::to_string(&res).unwrap_or_else(|_| r#""1920x1080""#.to_string())
-        }
-        "set_resolution" => {
-            let res = parsed.get("value").and_then(|v| v.as_str()).unwrap_or("1920x1080");
-            let mut stats = StatsApi::new();
-            stats.set_resolution(res);
-            r#"{"success": true}"#.to_string()
-        }
-        "get_key" => {
-            let key_name = parsed.get("name").and_then(|v| v.as_str()).unwrap_or("");
-            match get_key(key_name) {
-                Some(k) => serde_json::to_string(&k).unwrap_or_else(|_| r#""""#.to_string()),
-                None => r#""""#.to_string(),
-            }
+
+    let parsed = match validate_ipc(origin, message, DASHBOARD_ALLOWED_ACTIONS.as_slice()) {
+        Ok(parsed) => parsed,
+        Err(IpcError::InvalidJson) => {
+            return (fallback_id(), r#"{"error": "Invalid JSON"}"#.to_string())
         }
-        "capture_key_for" => {
-            // For now, return a placeholder - actual key capture requires native keyboard hooks
-            // This would need to be implemented with a proper key capture mechanism
-            let key_name = parsed.get("name").and_then(|v| v.as_str()).unwrap_or("");
-            let current = get_key(key_name).unwrap_or_else(|| "F9".to_string());
-            serde_json::to_string(&current).unwrap_or_else(|_| r#""F9""#.to_string())
-        }
-        "set_debug_overlay" => {
-            let value = parsed.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
-            let mut stats = StatsApi::new();
-            stats.set_show_debug_overlay(value);
-            r#"{"success": true}"#.to_string()
-        }
-        "get_debug_overlay" => {
-            let stats = StatsApi::new();
-            let value = stats.get_show_debug_overlay();
-            serde_json::to_string(&value).unwrap_or_else(|_| "true".to_string())
-        }
-        "set_overlay_on_top" => {
-            let value = parsed.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
-            let mut stats = StatsApi::new();
-            stats.set_overlay_always_on_top(value);
-            r#"{"success": true}"#.to_string()
-        }
-        "get_overlay_on_top" => {
-            let stats = StatsApi::new();
-            let value = stats.get_overlay_always_on_top();
-            serde_json::to_string(&value).unwrap_or_else(|_| "true".to_string())
-        }
-        "set_show_overlay" => {
-            let value = parsed.get("value").and_then(|v| v.as_bool()).unwrap_or(true);
-            let mut stats = StatsApi::new();
-            stats.set_show_overlay(value);
-            r#"{"success": true}"#.to_string()
-        }
-        "get_show_overlay" => {
-            let stats = StatsApi::new();
-            let value = stats.get_show_overlay();
-            serde_json::to_string(&value).unwrap_or_else(|_| "true".to_string())
-        }
-        "set_auto_bait" | "set_auto_rod" => {
-            // TODO: These settings need full implementation
-            r#"{"success": true}"#.to_string()
-        }
-        _ => {
-            format!(r#"{{"error": "Unknown action: {}"}}"#, action)
+        Err(IpcError::MissingAction) => {
+            return (fallback_id(), r#"{"error": "Missing action"}"#.to_string())
         }
-    }
+        Err(e) => {
+            tracing::warn!("Dashboard IPC: rejected message: {}", e);
+            return (fallback_id(), format!(r#"{{"error": "{}"}}"#, e));
+        }
+    };
+
+    let id = parsed.id.clone();
+    let result = match commands::dispatch(&parsed) {
+        Ok(value) => value.to_string(),
+        Err(e) => format!(r#"{{"error": "{}"}}"#, e),
+    };
+
+    (id, result)
+}
+
+/// Generate the `window.pywebview.api` method table from `commands::specs()`
+/// so the JS bridge can't drift out of sync with the registered actions.
+#[cfg(all(feature = "gui", windows))]
+fn generate_api_methods() -> String {
+    crate::ui::commands::specs()
+        .map(|(name, params)| {
+            let args = params.join(", ");
+            let fields = params
+                .iter()
+                .map(|p| format!("{p}: {p}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "            {name}: function({args}) {{\n                return callApi('{name}', {{ {fields} }});\n            }}"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+/// Stamp the persisted theme name into the `__THEME__` placeholder in
+/// `<html data-theme="__THEME__">` so the page paints in the right theme from
+/// the first frame, instead of flashing the default theme and restyling once
+/// JS loads. A no-op for custom HTML loaded from disk that doesn't contain
+/// the placeholder.
+#[cfg(all(feature = "gui", windows))]
+fn inject_theme(html: &str, theme: &str) -> String {
+    html.replace("__THEME__", theme)
 }
 
 /// Inject the pywebview API bridge into HTML
@@ -221,265 +196,218 @@ fn inject_api_bridge(html: &str) -> String {
 <script>
 // API Bridge for Blue Mancing Dashboard
 (function() {
-    // Promise-based API that uses IPC
+    // Promise-based API that uses request/response IPC: each call gets a
+    // monotonic id, the pending resolve/reject pair is stashed here, and
+    // window.__resolveApi (invoked by Rust via evaluate_script) settles it.
     const pendingRequests = new Map();
+    const REQUEST_TIMEOUT_MS = 10000;
     let requestId = 0;
-    
-    // Create the pywebview.api object
+
+    window.__resolveApi = function(id, payload) {
+        const pending = pendingRequests.get(id);
+        if (!pending) {
+            return;
+        }
+        pendingRequests.delete(id);
+        clearTimeout(pending.timeoutHandle);
+
+        if (payload && typeof payload === 'object' && 'error' in payload) {
+            pending.reject(new Error(payload.error));
+        } else {
+            pending.resolve(payload);
+        }
+    };
+
+    // Create the pywebview.api object. Method list is generated from the
+    // Rust-side command registry (see generate_api_methods) so it can't
+    // silently fall out of sync with what the IPC handler actually accepts.
     window.pywebview = {
         api: {
-            get_guide: function() {
-                return callApi('get_guide', {});
-            },
-            get_daily_table: function() {
-                return callApi('get_daily_table', {});
-            },
-            get_overall_summary: function() {
-                return callApi('get_overall_summary', {});
-            },
-            get_resolution: function() {
-                return callApi('get_resolution', {});
-            },
-            set_resolution: function(value) {
-                return callApi('set_resolution', { value: value });
-            },
-            get_key: function(name) {
-                return callApi('get_key', { name: name });
-            },
-            capture_key_for: function(name) {
-                return callApi('capture_key_for', { name: name });
-            },
-            set_auto_bait: function(value) {
-                return callApi('set_auto_bait', { value: value });
-            },
-            set_auto_rod: function(value) {
-                return callApi('set_auto_rod', { value: value });
-            },
-            set_debug_overlay: function(value) {
-                // Update preloaded value
-                window.__bluemancing_settings.show_debug_overlay = value;
-                return callApi('set_debug_overlay', { value: value });
-            },
-            get_debug_overlay: function() {
-                return callApi('get_debug_overlay', {});
-            },
-            set_overlay_on_top: function(value) {
-                // Update preloaded value
-                window.__bluemancing_settings.overlay_always_on_top = value;
-                return callApi('set_overlay_on_top', { value: value });
-            },
-            get_overlay_on_top: function() {
-                return callApi('get_overlay_on_top', {});
-            },
-            set_show_overlay: function(value) {
-                // Update preloaded value
-                window.__bluemancing_settings.show_overlay = value;
-                return callApi('set_show_overlay', { value: value });
-            },
-            get_show_overlay: function() {
-                return callApi('get_show_overlay', {});
-            }
+__API_METHODS__
         }
     };
-    
-    // Call API via IPC - uses synchronous XMLHttpRequest workaround for wry
+
+    // Call the Rust-side API over request/response IPC. Each call gets a
+    // unique id; the returned promise resolves (or rejects) when Rust
+    // echoes that id back through window.__resolveApi.
     function callApi(action, params) {
         return new Promise((resolve, reject) => {
+            const id = ++requestId;
+            const timeoutHandle = setTimeout(function() {
+                if (pendingRequests.delete(id)) {
+                    reject(new Error('API call "' + action + '" timed out'));
+                }
+            }, REQUEST_TIMEOUT_MS);
+
+            pendingRequests.set(id, { resolve, reject, timeoutHandle });
+
             try {
-                const message = JSON.stringify({ action: action, ...params });
-                
-                // Use ipc.postMessage for wry
+                const message = JSON.stringify({ id: id, action: action, ...params });
                 if (window.ipc) {
                     window.ipc.postMessage(message);
+                } else {
+                    throw new Error('window.ipc is unavailable');
                 }
-                
-                // Note: wry IPC is one-way (JS -> Rust), so we use preloaded data
-                // for immediate display. The IPC call triggers a refresh on the Rust side.
-                // Data is preloaded at startup and returned synchronously from cache.
-                const result = callApiSync(action, params);
-                resolve(result);
             } catch (e) {
-                console.error('API call failed:', e);
+                pendingRequests.delete(id);
+                clearTimeout(timeoutHandle);
                 reject(e);
             }
         });
     }
-    
-    // Get data from preloaded cache (loaded at startup by Rust)
-    function callApiSync(action, params) {
-        const message = JSON.stringify({ action: action, ...params });
-        
-        // Send via IPC
-        if (window.ipc) {
-            window.ipc.postMessage(message);
-        }
-        
-        // Return cached/inline data for immediate response
-        // The actual data will be loaded on first call
-        return getInlineData(action, params);
-    }
-    
-    // Get inline data (preloaded by Rust)
-    function getInlineData(action, params) {
-        switch(action) {
-            case 'get_guide':
-                return window.__bluemancing_guide || '';
-            case 'get_daily_table':
-                return window.__bluemancing_daily || '<p>Loading daily data...</p>';
-            case 'get_overall_summary':
-                return window.__bluemancing_summary || '<p>Loading summary...</p>';
-            case 'get_resolution':
-                return window.__bluemancing_resolution || '1920x1080';
-            case 'get_key':
-                return window.__bluemancing_keys && window.__bluemancing_keys[params.name] || '';
-            case 'get_debug_overlay':
-                return window.__bluemancing_settings && window.__bluemancing_settings.show_debug_overlay;
-            case 'get_overlay_on_top':
-                return window.__bluemancing_settings && window.__bluemancing_settings.overlay_always_on_top;
-            case 'get_show_overlay':
-                return window.__bluemancing_settings && window.__bluemancing_settings.show_overlay;
-            default:
-                return null;
-        }
-    }
-    
+
     // Signal that pywebview is ready
     setTimeout(function() {
         window.dispatchEvent(new Event('pywebviewready'));
     }, 100);
 })();
 </script>
-"#;
-    
-    // Also inject preloaded data
-    let guide_data = get_guide_html();
-    let daily_data = get_daily_html();
-    let summary_data = get_summary_html();
-    let resolution = get_resolution_value();
-    let keys_data = get_keys_json();
-    let settings_data = get_overlay_settings_json();
-    
-    let preload_script = format!(r#"
-<script>
-// Preloaded data for immediate display
-window.__bluemancing_guide = {};
-window.__bluemancing_daily = {};
-window.__bluemancing_summary = {};
-window.__bluemancing_resolution = {};
-window.__bluemancing_keys = {};
-window.__bluemancing_settings = {};
-</script>
-"#, 
-        serde_json::to_string(&guide_data).unwrap_or_else(|_| "\"\"".to_string()),
-        serde_json::to_string(&daily_data).unwrap_or_else(|_| "\"\"".to_string()),
-        serde_json::to_string(&summary_data).unwrap_or_else(|_| "\"\"".to_string()),
-        serde_json::to_string(&resolution).unwrap_or_else(|_| "\"1920x1080\"".to_string()),
-        keys_data,
-        settings_data
-    );
-    
+"#
+    .replace("__API_METHODS__", &generate_api_methods());
+
     // Inject before </head> tag
     if let Some(pos) = html.find("</head>") {
         let mut result = html.to_string();
-        result.insert_str(pos, &preload_script);
-        result.insert_str(pos, api_bridge);
+        result.insert_str(pos, &api_bridge);
         result
     } else {
         // If no </head> found, prepend
-        format!("{}{}{}", api_bridge, preload_script, html)
+        format!("{}{}", api_bridge, html)
     }
 }
 
-/// Get guide HTML content
+/// Which native file dialog to run for a dialog-backed dashboard action, and
+/// what `run_dialog` should do with the path the user picks.
 #[cfg(all(feature = "gui", windows))]
-fn get_guide_html() -> String {
-    use pulldown_cmark::{Parser, Options, html};
-    
-    let base = get_data_dir();
-    let guide_path = base.join("GUIDE.md");
-    
-    let markdown = match fs::read_to_string(&guide_path) {
-        Ok(content) => content,
-        Err(_) => {
-            // Try current directory as fallback
-            match fs::read_to_string("GUIDE.md") {
-                Ok(content) => content,
-                Err(_) => "# Guide\n\nGuide content not found.".to_string(),
-            }
-        }
-    };
-    
-    // Convert markdown to HTML
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    let parser = Parser::new_ext(&markdown, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    
-    format!(r#"<div class="intro-card">{}</div>"#, html_output)
+#[derive(Debug, Clone)]
+enum DialogKind {
+    ExportConfig,
+    ImportConfig,
+    SetGuidePath,
 }
 
-/// Get daily stats HTML
 #[cfg(all(feature = "gui", windows))]
-fn get_daily_html() -> String {
-    use crate::ui::stats_api::StatsApi;
-    let mut stats = StatsApi::new();
-    stats.get_daily_table()
+impl DialogKind {
+    /// Map a dashboard action name to its dialog, if it's one of the
+    /// dialog-backed actions.
+    fn from_action(action: &str) -> Option<Self> {
+        match action {
+            "export_config" => Some(DialogKind::ExportConfig),
+            "import_config" => Some(DialogKind::ImportConfig),
+            "set_guide_path" => Some(DialogKind::SetGuidePath),
+            _ => None,
+        }
+    }
 }
 
-/// Get summary HTML
+/// Custom event types for the event loop
 #[cfg(all(feature = "gui", windows))]
-fn get_summary_html() -> String {
-    use crate::ui::stats_api::StatsApi;
-    let mut stats = StatsApi::new();
-    stats.get_overall_summary()
+#[derive(Debug, Clone)]
+enum UserEvent {
+    /// A named event with a JSON payload, forwarded from
+    /// `utils::event_bus` and dispatched to the webviews as a
+    /// `CustomEvent`, replacing the old fixed-interval overlay refresh.
+    Emit { event: String, json: String },
+    /// A dialog-backed dashboard action (`export_config`, `import_config`,
+    /// `set_guide_path`). `wry`/`tao` require native file dialogs to run on
+    /// the event loop's own thread, so these are routed here instead of
+    /// handled inline in the IPC closure; the result is resolved back
+    /// through `window.__resolveApi(reply_id, ...)` once the dialog closes.
+    OpenDialog { kind: DialogKind, reply_id: String },
 }
 
-/// Get resolution value
+/// Run a native file dialog for `kind` on the calling (event loop) thread,
+/// performing whatever read/write the action implies, and return the JSON
+/// payload to resolve the pending IPC promise with.
 #[cfg(all(feature = "gui", windows))]
-fn get_resolution_value() -> String {
-    use crate::ui::stats_api::StatsApi;
-    let stats = StatsApi::new();
-    stats.get_resolution()
+fn run_dialog(kind: DialogKind) -> serde_json::Value {
+    match kind {
+        DialogKind::ExportConfig => export_config_dialog(),
+        DialogKind::ImportConfig => import_config_dialog(),
+        DialogKind::SetGuidePath => set_guide_path_dialog(),
+    }
 }
 
-/// Get all keys as JSON
+/// Let the user save the current `settings.json` (keybinds, resolution,
+/// overlay toggles) to a location of their choosing.
 #[cfg(all(feature = "gui", windows))]
-fn get_keys_json() -> String {
-    use crate::utils::keybinds::get_key;
-    
-    let key_names = ["start_key", "stop_key", "fish_key", "bait_key", "rods_key", "esc_key", "left_key", "right_key"];
-    let mut keys = std::collections::HashMap::new();
-    
-    for name in &key_names {
-        if let Some(value) = get_key(name) {
-            keys.insert(name.to_string(), value);
-        }
+fn export_config_dialog() -> serde_json::Value {
+    let settings_path = get_data_dir().join("config").join("settings.json");
+    let content = fs::read_to_string(&settings_path).unwrap_or_else(|_| "{}".to_string());
+
+    let Some(dest) = rfd::FileDialog::new()
+        .set_file_name("blue-mancing-settings.json")
+        .add_filter("JSON", &["json"])
+        .save_file()
+    else {
+        return serde_json::json!({ "error": "cancelled" });
+    };
+
+    match fs::write(&dest, content) {
+        Ok(_) => serde_json::json!({ "path": dest.display().to_string() }),
+        Err(e) => serde_json::json!({
+            "error": format!("failed to write {}: {}", dest.display(), e)
+        }),
     }
-    
-    serde_json::to_string(&keys).unwrap_or_else(|_| "{}".to_string())
 }
 
-/// Get overlay settings as JSON
+/// Let the user pick a previously exported `settings.json` and replace the
+/// current one with it.
 #[cfg(all(feature = "gui", windows))]
-fn get_overlay_settings_json() -> String {
-    use crate::ui::stats_api::StatsApi;
-    
-    let stats = StatsApi::new();
-    let mut settings = std::collections::HashMap::new();
-    
-    settings.insert("show_debug_overlay".to_string(), stats.get_show_debug_overlay());
-    settings.insert("overlay_always_on_top".to_string(), stats.get_overlay_always_on_top());
-    settings.insert("show_overlay".to_string(), stats.get_show_overlay());
-    
-    serde_json::to_string(&settings).unwrap_or_else(|_| r#"{"show_debug_overlay":true,"overlay_always_on_top":true,"show_overlay":true}"#.to_string())
+fn import_config_dialog() -> serde_json::Value {
+    let Some(src) = rfd::FileDialog::new().add_filter("JSON", &["json"]).pick_file() else {
+        return serde_json::json!({ "error": "cancelled" });
+    };
+
+    let content = match fs::read_to_string(&src) {
+        Ok(content) => content,
+        Err(e) => {
+            return serde_json::json!({
+                "error": format!("failed to read {}: {}", src.display(), e)
+            })
+        }
+    };
+
+    if serde_json::from_str::<serde_json::Value>(&content).is_err() {
+        return serde_json::json!({ "error": "selected file is not valid JSON" });
+    }
+
+    let settings_path = get_data_dir().join("config").join("settings.json");
+    if let Some(parent) = settings_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    match fs::write(&settings_path, content) {
+        Ok(_) => serde_json::json!({ "path": src.display().to_string() }),
+        Err(e) => serde_json::json!({ "error": format!("failed to write settings: {}", e) }),
+    }
 }
 
-/// Custom event types for the event loop
+/// Let the user pick a Markdown file to replace `GUIDE.md` with, for
+/// swapping in a custom in-app guide.
 #[cfg(all(feature = "gui", windows))]
-#[derive(Debug, Clone)]
-enum UserEvent {
-    UpdateOverlay,
+fn set_guide_path_dialog() -> serde_json::Value {
+    let Some(src) = rfd::FileDialog::new()
+        .add_filter("Markdown", &["md"])
+        .pick_file()
+    else {
+        return serde_json::json!({ "error": "cancelled" });
+    };
+
+    let content = match fs::read_to_string(&src) {
+        Ok(content) => content,
+        Err(e) => {
+            return serde_json::json!({
+                "error": format!("failed to read {}: {}", src.display(), e)
+            })
+        }
+    };
+
+    let guide_path = get_data_dir().join("GUIDE.md");
+    match fs::write(&guide_path, content) {
+        Ok(_) => serde_json::json!({ "path": src.display().to_string() }),
+        Err(e) => serde_json::json!({ "error": format!("failed to write GUIDE.md: {}", e) }),
+    }
 }
 
 /// Start the UI (main entry point)
@@ -490,20 +418,21 @@ pub fn start_ui() {
         event_loop::{ControlFlow, EventLoopBuilder, EventLoopProxy},
         window::WindowBuilder,
     };
-    use wry::WebViewBuilder;
-    use std::sync::mpsc;
+    use wry::{WebView, WebViewBuilder};
     use std::thread;
-    use std::time::Duration;
-    
+
     let base = get_data_dir();
     let html_path = base.join("html");
     
     // Load HTML content - prefer the updated overlay HTML with IPC support
+    let theme = StatsApi::new().get_theme();
     let overlay_html = fs::read_to_string(html_path.join("overlay.html"))
         .unwrap_or_else(|_| get_default_overlay_html().to_string());
+    let overlay_html = inject_theme(&overlay_html, &theme);
     let main_html = fs::read_to_string(html_path.join("main.html"))
         .unwrap_or_else(|_| get_default_main_html().to_string());
-    
+    let main_html = inject_theme(&main_html, &theme);
+
     let event_loop = EventLoopBuilder::<UserEvent>::with_user_event().build();
     let proxy = event_loop.create_proxy();
     
@@ -519,21 +448,105 @@ pub fn start_ui() {
     // Inject the pywebview API bridge into the main HTML
     let main_html_with_api = inject_api_bridge(&main_html);
     
-    // Build main webview with IPC handler for dashboard API calls
+    // Build main webview with IPC handler for dashboard API calls. The
+    // webview isn't constructed yet when the handler closure is created, so
+    // it resolves requests through this slot, filled in right after `build`.
+    let main_webview_slot: Arc<parking_lot::Mutex<Option<WebView>>> =
+        Arc::new(parking_lot::Mutex::new(None));
+    let ipc_webview_slot = Arc::clone(&main_webview_slot);
+    let event_main_webview_slot = Arc::clone(&main_webview_slot);
+    let capture_webview_slot = Arc::clone(&main_webview_slot);
+    let dialog_proxy = proxy.clone();
     let main_webview = WebViewBuilder::new()
         .with_html(&main_html_with_api)
         .with_ipc_handler(move |request| {
-            let message = request.body();
-            // IPC handler processes requests but responses are preloaded in HTML
-            // The response is logged for debugging purposes
-            let response = handle_dashboard_ipc(message);
-            tracing::debug!("Dashboard IPC: {} -> {}", message, response);
+            let origin = request.uri().to_string();
+            let message = request.body().to_string();
+
+            let action = serde_json::from_str::<serde_json::Value>(&message)
+                .ok()
+                .and_then(|v| v.get("action").and_then(|a| a.as_str().map(str::to_string)));
+
+            // Export/import/set-guide-path open a native file dialog, which
+            // wry/tao require to run on the event loop's own thread rather
+            // than this IPC callback, so they're forwarded as a user event
+            // and resolved once the dialog closes.
+            if let Some(kind) = action.as_deref().and_then(DialogKind::from_action) {
+                let parsed = match crate::ui::ipc_guard::validate_ipc(
+                    &origin,
+                    &message,
+                    DASHBOARD_ALLOWED_ACTIONS.as_slice(),
+                ) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        tracing::warn!("Dashboard IPC: rejected dialog request: {}", e);
+                        return;
+                    }
+                };
+
+                let _ = dialog_proxy.send_event(UserEvent::OpenDialog {
+                    kind,
+                    reply_id: parsed.id,
+                });
+                return;
+            }
+
+            // Key capture blocks on the next keypress (up to a few seconds),
+            // so it's validated and handled here on its own thread, and
+            // resolves the pending promise itself once it completes instead
+            // of blocking this handler (and every other IPC call) for the
+            // capture's duration.
+            let is_capture = action.as_deref() == Some("capture_key_for");
+
+            if is_capture {
+                let parsed = match crate::ui::ipc_guard::validate_ipc(
+                    &origin,
+                    &message,
+                    DASHBOARD_ALLOWED_ACTIONS.as_slice(),
+                ) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        tracing::warn!("Dashboard IPC: rejected capture request: {}", e);
+                        return;
+                    }
+                };
+
+                let name = parsed.str("name").unwrap_or("").to_string();
+                let id = parsed.id.clone();
+                let webview_slot = Arc::clone(&capture_webview_slot);
+                thread::spawn(move || {
+                    let outcome = crate::utils::key_capture::capture_key_for(&name);
+                    let payload = match outcome {
+                        crate::utils::key_capture::CaptureOutcome::Captured(key) => {
+                            serde_json::json!({ "key": key })
+                        }
+                        crate::utils::key_capture::CaptureOutcome::Cancelled => {
+                            serde_json::json!({ "error": "cancelled" })
+                        }
+                        crate::utils::key_capture::CaptureOutcome::TimedOut => {
+                            serde_json::json!({ "error": "timed out" })
+                        }
+                    };
+                    let js = format!("window.__resolveApi({}, {});", id, payload);
+                    if let Some(webview) = webview_slot.lock().as_ref() {
+                        let _ = webview.evaluate_script(&js);
+                    }
+                });
+                return;
+            }
+
+            let (id, response) = handle_dashboard_ipc(&origin, &message);
+            tracing::debug!("Dashboard IPC: {} -> id={} {}", message, id, response);
+
+            let js = format!("window.__resolveApi({}, {});", id, response);
+            if let Some(webview) = ipc_webview_slot.lock().as_ref() {
+                let _ = webview.evaluate_script(&js);
+            }
         })
         .build(&main_window)
         .expect("Failed to create main webview");
-    
-    // Store main webview for evaluating scripts
-    let main_webview = Arc::new(parking_lot::Mutex::new(main_webview));
+
+    *main_webview_slot.lock() = Some(main_webview);
     
     register_window(Window::Main, WindowHandle {
         title: "Blue Mancing - Dashboard".to_string(),
@@ -555,8 +568,9 @@ pub fn start_ui() {
     let overlay_webview = WebViewBuilder::new()
         .with_html(&overlay_html)
         .with_ipc_handler(move |request| {
+            let origin = request.uri().to_string();
             let message = request.body();
-            if let Some(response) = handle_ipc_message(message) {
+            if let Some(response) = handle_ipc_message(&origin, message) {
                 tracing::debug!("IPC response: {}", response);
             }
         })
@@ -571,32 +585,70 @@ pub fn start_ui() {
         title: "Blue Mancing".to_string(),
     });
     
-    // Spawn a thread to periodically update the overlay with bot status
-    // Using 250ms interval to balance responsiveness and CPU usage
+    // Forward events pushed onto the event bus (e.g. by SHARED_STATE setters
+    // when a value actually changes) into the event loop, instead of a
+    // fixed-interval thread that re-serialized and pushed status whether or
+    // not anything changed. The remote WebSocket broadcaster subscribes to
+    // the same bus independently, so this doesn't steal events from it.
+    let events = crate::utils::event_bus::subscribe();
     let proxy_clone = proxy.clone();
     thread::spawn(move || {
-        loop {
-            thread::sleep(Duration::from_millis(250));
-            
-            // Send update event to main thread
-            let _ = proxy_clone.send_event(UserEvent::UpdateOverlay);
+        for bot_event in events {
+            let _ = proxy_clone.send_event(UserEvent::Emit {
+                event: bot_event.name,
+                json: bot_event.payload.to_string(),
+            });
         }
     });
-    
+
+    // Send an initial snapshot so newly opened webviews aren't blank until
+    // the first state change fires.
+    let _ = proxy.send_event(UserEvent::Emit {
+        event: "bot-status".to_string(),
+        json: SHARED_STATE.to_json(),
+    });
+
     // Run event loop
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
-        
+
         match event {
-            Event::UserEvent(UserEvent::UpdateOverlay) => {
-                // Update overlay with current bot status
-                let status_json = SHARED_STATE.to_json();
+            Event::UserEvent(UserEvent::Emit { event, json }) => {
+                // Dispatch as a CustomEvent so both the overlay and dashboard
+                // JS can addEventListener(event, ...) for the payloads they
+                // care about (e.g. "bot-status", "catch-logged").
                 let js = format!(
-                    "if (window.updateFromRust) {{ window.updateFromRust({}); }}",
-                    status_json
+                    "window.dispatchEvent(new CustomEvent({}, {{ detail: {} }}));",
+                    serde_json::to_string(&event).unwrap_or_else(|_| "\"event\"".to_string()),
+                    json
                 );
-                
+
+                // The overlay's legacy updateFromRust hook still expects a
+                // direct call on "bot-status" updates.
+                let overlay_js = if event == "bot-status" {
+                    format!(
+                        "{} if (window.updateFromRust) {{ window.updateFromRust({}); }}",
+                        js, json
+                    )
+                } else {
+                    js.clone()
+                };
+
                 if let Some(webview) = overlay_webview_clone.try_lock() {
+                    let _ = webview.evaluate_script(&overlay_js);
+                }
+                if let Some(webview) = event_main_webview_slot.lock().as_ref() {
+                    let _ = webview.evaluate_script(&js);
+                }
+            }
+            Event::UserEvent(UserEvent::OpenDialog { kind, reply_id }) => {
+                // Runs on the event loop thread, as wry/tao require for
+                // native dialogs, blocking this thread until the user
+                // closes it - acceptable since no other UI work happens
+                // while a modal file dialog is open anyway.
+                let payload = run_dialog(kind);
+                let js = format!("window.__resolveApi({}, {});", reply_id, payload);
+                if let Some(webview) = event_main_webview_slot.lock().as_ref() {
                     let _ = webview.evaluate_script(&js);
                 }
             }
@@ -632,15 +684,78 @@ pub fn start_ui() {
 /// Default overlay HTML with IPC support for wry
 fn get_default_overlay_html() -> &'static str {
     r#"<!DOCTYPE html>
-<html>
+<html data-theme="__THEME__">
 <head>
     <meta charset="utf-8">
     <title>Blue Mancing Overlay</title>
     <style>
+        /* Theme variable sets - modeled on rustdoc's dark/light/ayu switcher.
+           Everything below reads these instead of hard-coding colors, so
+           switching `data-theme` on <html> restyles instantly with no reload. */
+        :root, html[data-theme="dark"] {
+            --bg-gradient-start: #1a1a2e;
+            --bg-gradient-end: #16213e;
+            --text-color: #fff;
+            --title-color: #38c6ff;
+            --btn-start-bg: #22c55e;
+            --btn-start-hover-bg: #16a34a;
+            --btn-stop-bg: #ef4444;
+            --btn-stop-hover-bg: #dc2626;
+            --stat-row-bg: rgba(255,255,255,0.1);
+            --label-color: #aaa;
+            --value-color: #00ffc8;
+            --status-section-bg: rgba(0,0,0,0.3);
+            --status-dot-bg: #ef4444;
+            --status-running-color: #22c55e;
+            --status-stopped-color: #ef4444;
+            --activity-color: #94a3b8;
+            --activity-bg: rgba(255,255,255,0.05);
+            --timer-color: #60a5fa;
+        }
+        html[data-theme="light"] {
+            --bg-gradient-start: #f4f6fb;
+            --bg-gradient-end: #e4e9f2;
+            --text-color: #1a1a2e;
+            --title-color: #1d6fa5;
+            --btn-start-bg: #16a34a;
+            --btn-start-hover-bg: #15803d;
+            --btn-stop-bg: #dc2626;
+            --btn-stop-hover-bg: #b91c1c;
+            --stat-row-bg: rgba(0,0,0,0.05);
+            --label-color: #64748b;
+            --value-color: #0d9488;
+            --status-section-bg: rgba(0,0,0,0.04);
+            --status-dot-bg: #dc2626;
+            --status-running-color: #15803d;
+            --status-stopped-color: #dc2626;
+            --activity-color: #475569;
+            --activity-bg: rgba(0,0,0,0.03);
+            --timer-color: #2563eb;
+        }
+        html[data-theme="ayu"] {
+            --bg-gradient-start: #0f1419;
+            --bg-gradient-end: #131721;
+            --text-color: #e6e1cf;
+            --title-color: #ffb454;
+            --btn-start-bg: #7fd962;
+            --btn-start-hover-bg: #6bc24f;
+            --btn-stop-bg: #f07178;
+            --btn-stop-hover-bg: #d85a61;
+            --stat-row-bg: rgba(255,180,84,0.08);
+            --label-color: #b8cfe0;
+            --value-color: #95e6cb;
+            --status-section-bg: rgba(0,0,0,0.35);
+            --status-dot-bg: #f07178;
+            --status-running-color: #7fd962;
+            --status-stopped-color: #f07178;
+            --activity-color: #b8cfe0;
+            --activity-bg: rgba(255,180,84,0.05);
+            --timer-color: #59c2ff;
+        }
         body {
             font-family: 'Segoe UI', sans-serif;
-            background: linear-gradient(135deg, #1a1a2e 0%, #16213e 100%);
-            color: #fff;
+            background: linear-gradient(135deg, var(--bg-gradient-start) 0%, var(--bg-gradient-end) 100%);
+            color: var(--text-color);
             margin: 0;
             padding: 10px;
         }
@@ -653,11 +768,12 @@ fn get_default_overlay_html() -> &'static str {
         .title {
             font-size: 14px;
             font-weight: bold;
-            color: #38c6ff;
+            color: var(--title-color);
         }
         .controls {
             display: flex;
             gap: 8px;
+            align-items: center;
         }
         .btn {
             padding: 6px 14px;
@@ -669,15 +785,23 @@ fn get_default_overlay_html() -> &'static str {
             transition: all 0.2s;
         }
         .btn-start {
-            background: #22c55e;
+            background: var(--btn-start-bg);
             color: white;
         }
-        .btn-start:hover { background: #16a34a; }
+        .btn-start:hover { background: var(--btn-start-hover-bg); }
         .btn-stop {
-            background: #ef4444;
+            background: var(--btn-stop-bg);
             color: white;
         }
-        .btn-stop:hover { background: #dc2626; }
+        .btn-stop:hover { background: var(--btn-stop-hover-bg); }
+        #theme-select {
+            font-size: 11px;
+            background: var(--stat-row-bg);
+            color: var(--text-color);
+            border: none;
+            border-radius: 4px;
+            padding: 4px 6px;
+        }
         .stats {
             display: grid;
             grid-template-columns: 1fr 1fr;
@@ -687,14 +811,14 @@ fn get_default_overlay_html() -> &'static str {
         .stat-row {
             display: flex;
             justify-content: space-between;
-            background: rgba(255,255,255,0.1);
+            background: var(--stat-row-bg);
             padding: 6px 10px;
             border-radius: 4px;
         }
-        .label { color: #aaa; font-size: 12px; }
-        .value { color: #00ffc8; font-weight: bold; font-size: 12px; }
+        .label { color: var(--label-color); font-size: 12px; }
+        .value { color: var(--value-color); font-weight: bold; font-size: 12px; }
         .status-section {
-            background: rgba(0,0,0,0.3);
+            background: var(--status-section-bg);
             border-radius: 6px;
             padding: 8px;
         }
@@ -713,9 +837,9 @@ fn get_default_overlay_html() -> &'static str {
             width: 10px;
             height: 10px;
             border-radius: 50%;
-            background: #ef4444;
+            background: var(--status-dot-bg);
         }
-        .status-dot.running { background: #22c55e; animation: pulse 1.5s infinite; }
+        .status-dot.running { background: var(--status-running-color); animation: pulse 1.5s infinite; }
         @keyframes pulse {
             0%, 100% { opacity: 1; }
             50% { opacity: 0.5; }
@@ -724,32 +848,65 @@ fn get_default_overlay_html() -> &'static str {
             font-size: 13px;
             font-weight: bold;
         }
-        .status-text.running { color: #22c55e; }
-        .status-text.stopped { color: #ef4444; }
+        .status-text.running { color: var(--status-running-color); }
+        .status-text.stopped { color: var(--status-stopped-color); }
         .activity {
             font-size: 11px;
-            color: #94a3b8;
+            color: var(--activity-color);
             margin-top: 4px;
             padding: 4px 8px;
-            background: rgba(255,255,255,0.05);
+            background: var(--activity-bg);
             border-radius: 4px;
             min-height: 16px;
         }
         .timer {
             font-size: 12px;
-            color: #60a5fa;
+            color: var(--timer-color);
+        }
+        .history-section {
+            margin-top: 8px;
+            background: var(--status-section-bg);
+            border-radius: 6px;
+            padding: 8px;
+        }
+        .history-header {
+            display: flex;
+            justify-content: space-between;
+            font-size: 11px;
+            color: var(--label-color);
+            margin-bottom: 4px;
         }
+        #history-chart {
+            display: block;
+            width: 100%;
+            height: 60px;
+        }
+        .update-banner {
+            display: none;
+            margin-top: 8px;
+            font-size: 11px;
+            color: var(--label-color);
+            background: var(--activity-bg);
+            border-radius: 4px;
+            padding: 4px 8px;
+        }
+        .update-banner.visible { display: block; }
     </style>
 </head>
 <body>
     <div class="header">
         <span class="title">Blue Mancing</span>
         <div class="controls">
+            <select id="theme-select">
+                <option value="dark">Dark</option>
+                <option value="light">Light</option>
+                <option value="ayu">Ayu</option>
+            </select>
             <button class="btn btn-start" id="start-btn">Start</button>
             <button class="btn btn-stop" id="stop-btn">Stop</button>
         </div>
     </div>
-    
+
     <div class="stats">
         <div class="stat-row">
             <span class="label">Catches</span>
@@ -779,7 +936,17 @@ fn get_default_overlay_html() -> &'static str {
         </div>
         <div class="activity" id="activity">Waiting for start...</div>
     </div>
-    
+
+    <div class="history-section">
+        <div class="history-header">
+            <span>Catch rate</span>
+            <span id="history-latest">-</span>
+        </div>
+        <canvas id="history-chart" width="280" height="60"></canvas>
+    </div>
+
+    <div class="update-banner" id="update-banner"></div>
+
     <script>
         let startTime = null;
         let timerInterval = null;
@@ -844,8 +1011,67 @@ fn get_default_overlay_html() -> &'static str {
             if (data.detail) {
                 document.getElementById('activity').textContent = data.activity + ' - ' + data.detail;
             }
+
+            // Update the rate-over-time chart
+            if (data.history) {
+                renderHistoryChart(data.history);
+            }
+
+            // Update the self-updater status banner
+            if (data.update) {
+                renderUpdateBanner(data.update);
+            }
         };
-        
+
+        function renderUpdateBanner(update) {
+            const banner = document.getElementById('update-banner');
+            const messages = {
+                checking: 'Checking for updates...',
+                available: 'Update ' + update.version + ' available',
+                downloading: 'Downloading ' + update.version + '... ' + Math.round(update.progress) + '%',
+                error: 'Update check failed',
+            };
+            const message = messages[update.state];
+            if (!message) {
+                banner.classList.remove('visible');
+                return;
+            }
+            banner.textContent = message;
+            banner.classList.add('visible');
+        }
+
+        // Plain Canvas 2D line chart of catch rate over the current session's
+        // sampled history - no charting library needed for one line.
+        function renderHistoryChart(history) {
+            const canvas = document.getElementById('history-chart');
+            const ctx = canvas.getContext('2d');
+            const w = canvas.width;
+            const h = canvas.height;
+            ctx.clearRect(0, 0, w, h);
+
+            const latest = document.getElementById('history-latest');
+            if (!history.length) {
+                latest.textContent = '-';
+                return;
+            }
+            latest.textContent = history[history.length - 1].rate.toFixed(1) + '%';
+
+            const style = getComputedStyle(document.documentElement);
+            ctx.strokeStyle = style.getPropertyValue('--timer-color') || '#60a5fa';
+            ctx.lineWidth = 2;
+            ctx.beginPath();
+            history.forEach(function(sample, i) {
+                const x = history.length > 1 ? (i / (history.length - 1)) * w : w;
+                const y = h - (Math.min(Math.max(sample.rate, 0), 100) / 100) * h;
+                if (i === 0) {
+                    ctx.moveTo(x, y);
+                } else {
+                    ctx.lineTo(x, y);
+                }
+            });
+            ctx.stroke();
+        }
+
         function updateTimer() {
             if (!startTime) return;
             const diff = new Date() - startTime;
@@ -863,7 +1089,20 @@ fn get_default_overlay_html() -> &'static str {
         document.getElementById('stop-btn').addEventListener('click', function() {
             sendToRust('stop');
         });
-        
+
+        // Theme switcher - applies instantly (CSS variables, no reload) and
+        // persists the choice through the IPC bridge so it's restored on the
+        // next launch.
+        const themeSelect = document.getElementById('theme-select');
+        themeSelect.value = document.documentElement.getAttribute('data-theme') || 'dark';
+        themeSelect.addEventListener('change', function() {
+            const theme = themeSelect.value;
+            document.documentElement.setAttribute('data-theme', theme);
+            if (window.ipc) {
+                window.ipc.postMessage(JSON.stringify({ action: 'set_theme', value: theme }));
+            }
+        });
+
         // Legacy pywebview compatibility - map to new IPC
         window.pywebview = {
             api: {
@@ -881,20 +1120,35 @@ fn get_default_overlay_html() -> &'static str {
 /// Default main HTML
 fn get_default_main_html() -> &'static str {
     r#"<!DOCTYPE html>
-<html>
+<html data-theme="__THEME__">
 <head>
     <meta charset="utf-8">
     <title>Blue Mancing - Dashboard</title>
     <style>
+        :root, html[data-theme="dark"] {
+            --bg-color: #0a1628;
+            --text-color: #fff;
+            --title-color: #38c6ff;
+        }
+        html[data-theme="light"] {
+            --bg-color: #f4f6fb;
+            --text-color: #1a1a2e;
+            --title-color: #1d6fa5;
+        }
+        html[data-theme="ayu"] {
+            --bg-color: #0f1419;
+            --text-color: #e6e1cf;
+            --title-color: #ffb454;
+        }
         body {
             font-family: 'Segoe UI', sans-serif;
-            background: #0a1628;
-            color: #fff;
+            background: var(--bg-color);
+            color: var(--text-color);
             margin: 0;
             padding: 20px;
         }
         h1 {
-            color: #38c6ff;
+            color: var(--title-color);
             text-align: center;
         }
         .container {
@@ -0,0 +1,356 @@
+//! Audio-cue bite detection, an alternative to polling `catch_fish.png`.
+//!
+//! The bite-wait loop in `main_loop` normally re-captures the game window
+//! and template-matches `catch_fish.png` every `CHECK_INTERVAL`, which is
+//! fragile against UI animations (a fading-in icon can miss a few 0.9
+//! threshold matches) and costs a screen capture per tick. Some games make
+//! the bite far more obvious by ear than by eye, so this listens to the
+//! game's audio output with `cpal` instead, decodes a short recorded
+//! reference clip of the bite sound with `symphonia`, and compares a
+//! rolling amplitude envelope of the live capture against the reference's
+//! envelope. `BiteListener` only answers "has the bite sound happened since
+//! I last asked" - the caller still owns calling `state.update_progress()`
+//! and entering `post_catch_loop`, exactly as it does for a `catch_fish.png`
+//! match, so the two detection paths plug into the same call site.
+//!
+//! `cpal` has no portable "capture what's currently playing" API, so
+//! `find_loopback_device` looks for an input device that is actually a
+//! loopback/monitor of the output (e.g. PulseAudio/PipeWire's "Monitor of
+//! ..." devices on Linux, "Stereo Mix" on Windows). If none is found it
+//! falls back to the default input device - almost always a microphone -
+//! and logs a warning, since picking that up silently would otherwise look
+//! like a working bite detector that just never fires.
+
+#![allow(dead_code)]
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use parking_lot::Mutex;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+/// Width of the amplitude-envelope buckets used for signature matching.
+/// Short enough to catch a bite's attack transient, long enough to smooth
+/// over sample-rate jitter between the reference clip and the live capture.
+const ENVELOPE_BUCKET_MS: usize = 20;
+
+/// Which source(s) decide a bite has happened. Mirrors the repo's other
+/// flat string settings (`screen_reader::base::Settings::bite_detection_mode`)
+/// - `from_setting` is the one place that string gets parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BiteDetectionMode {
+    /// Only `find_image_in_window` on `catch_fish.png` - the original path.
+    Image,
+    /// Only `BiteListener::take_detected`.
+    Audio,
+    /// Either one firing counts as a bite.
+    Both,
+}
+
+impl BiteDetectionMode {
+    /// Parse a `bite_detection_mode` settings value, defaulting to `Image`
+    /// for anything unrecognized so an existing install's missing/garbled
+    /// setting doesn't silently stop matching on `catch_fish.png`.
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "audio" => Self::Audio,
+            "both" => Self::Both,
+            _ => Self::Image,
+        }
+    }
+
+    pub fn uses_image(self) -> bool {
+        matches!(self, Self::Image | Self::Both)
+    }
+
+    pub fn uses_audio(self) -> bool {
+        matches!(self, Self::Audio | Self::Both)
+    }
+}
+
+/// Decode a reference audio clip into an amplitude-envelope signature: one
+/// RMS value per `ENVELOPE_BUCKET_MS` window, averaged across channels.
+fn decode_envelope(path: &Path) -> Result<Vec<f32>, String> {
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {}", path.display(), e))?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|e| format!("failed to probe {}: {}", path.display(), e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .default_track()
+        .ok_or_else(|| format!("{} has no default audio track", path.display()))?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(48000) as usize;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("failed to build decoder for {}: {}", path.display(), e))?;
+
+    let bucket_len = (sample_rate * ENVELOPE_BUCKET_MS / 1000).max(1);
+    let mut envelope = Vec::new();
+    let mut bucket_sum = 0f64;
+    let mut bucket_count = 0usize;
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(_) => break,
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(_) => continue,
+        };
+
+        let spec = *decoded.spec();
+        let mut sample_buf = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        sample_buf.copy_interleaved_ref(decoded);
+
+        for frame in sample_buf.samples().chunks(spec.channels.count().max(1)) {
+            let mono = frame.iter().sum::<f32>() / frame.len() as f32;
+            bucket_sum += (mono * mono) as f64;
+            bucket_count += 1;
+            if bucket_count >= bucket_len {
+                envelope.push((bucket_sum / bucket_count as f64).sqrt() as f32);
+                bucket_sum = 0.0;
+                bucket_count = 0;
+            }
+        }
+    }
+
+    if bucket_count > 0 {
+        envelope.push((bucket_sum / bucket_count as f64).sqrt() as f32);
+    }
+
+    Ok(envelope)
+}
+
+/// Rolling state the input stream's callback updates on every buffer: a
+/// partially-filled amplitude bucket, and the most recent `reference.len()`
+/// completed buckets to compare against the reference envelope.
+struct RollingEnvelope {
+    bucket_sum: f32,
+    bucket_count: usize,
+    window: Vec<f32>,
+}
+
+impl RollingEnvelope {
+    fn new() -> Self {
+        Self {
+            bucket_sum: 0.0,
+            bucket_count: 0,
+            window: Vec::new(),
+        }
+    }
+}
+
+/// Name fragments that identify an input device as a loopback/monitor of the
+/// system output rather than a real microphone, across the hosts `cpal`
+/// supports.
+const LOOPBACK_NAME_HINTS: [&str; 4] = ["monitor of", "loopback", "stereo mix", "what u hear"];
+
+/// Find an input device that actually captures the system's audio output -
+/// a PulseAudio/PipeWire "Monitor of ..." source, Windows "Stereo Mix", or
+/// similar - by name. Falls back to the default input device (a microphone,
+/// on most setups) with a warning if no such device is found, since the game
+/// audio won't reach a mic unless the user has routed it there themselves.
+fn find_loopback_device(host: &cpal::Host) -> Result<cpal::Device, String> {
+    if let Ok(devices) = host.input_devices() {
+        for device in devices {
+            let Ok(name) = device.name() else { continue };
+            let lower = name.to_lowercase();
+            if LOOPBACK_NAME_HINTS.iter().any(|hint| lower.contains(hint)) {
+                tracing::info!("[AUDIO] Using loopback input device: {}", name);
+                return Ok(device);
+            }
+        }
+    }
+
+    let device = host
+        .default_input_device()
+        .ok_or_else(|| "no default audio input device available".to_string())?;
+    let device_name = device.name().unwrap_or_else(|_| "<unknown>".to_string());
+    tracing::warn!(
+        "[AUDIO] No loopback/monitor device found; falling back to the default input device ({}). \
+         This is almost always a microphone, not the game's audio - bite detection will only work \
+         if the game's output is routed into it (e.g. via a virtual audio cable).",
+        device_name
+    );
+    Ok(device)
+}
+
+/// Listens to an audio input device in the background and flags when a
+/// rolling window of the live capture matches `reference` closely enough.
+pub struct BiteListener {
+    detected: Arc<AtomicBool>,
+    // Kept alive for as long as the listener should keep capturing - cpal
+    // stops the stream when this is dropped.
+    _stream: cpal::Stream,
+}
+
+impl BiteListener {
+    /// Decode `reference_clip` and start capturing the game's audio, comparing
+    /// every rolling window against the reference envelope and setting the
+    /// detected flag once the normalized distance drops below `threshold`
+    /// (lower is stricter; the comparison is strict, so 0.0 never fires, and
+    /// the distance can exceed 1.0, so 1.0 does not match everything).
+    pub fn start(reference_clip: &Path, threshold: f32) -> Result<Self, String> {
+        let reference = decode_envelope(reference_clip)?;
+        if reference.is_empty() {
+            return Err(format!("{} decoded to an empty clip", reference_clip.display()));
+        }
+
+        let host = cpal::default_host();
+        let device = find_loopback_device(&host)?;
+        let config = device
+            .default_input_config()
+            .map_err(|e| format!("failed to read default input config: {}", e))?;
+
+        let sample_rate = config.sample_rate().0 as usize;
+        let channels = config.channels().max(1) as usize;
+        let bucket_len = (sample_rate * ENVELOPE_BUCKET_MS / 1000).max(1);
+
+        let detected = Arc::new(AtomicBool::new(false));
+        let rolling = Arc::new(Mutex::new(RollingEnvelope::new()));
+
+        let stream_detected = detected.clone();
+        let stream_rolling = rolling.clone();
+        let stream_reference = reference.clone();
+
+        let err_fn = |err| tracing::warn!("[AUDIO] bite listener stream error: {}", err);
+
+        let stream = device
+            .build_input_stream(
+                &config.into(),
+                move |data: &[f32], _| {
+                    push_samples(
+                        data,
+                        channels,
+                        bucket_len,
+                        threshold,
+                        &stream_rolling,
+                        &stream_reference,
+                        &stream_detected,
+                    );
+                },
+                err_fn,
+                None,
+            )
+            .map_err(|e| format!("failed to build input stream: {}", e))?;
+
+        stream
+            .play()
+            .map_err(|e| format!("failed to start input stream: {}", e))?;
+
+        Ok(Self {
+            detected,
+            _stream: stream,
+        })
+    }
+
+    /// Check whether a bite has been heard since the last call, clearing
+    /// the flag either way - the same "ask once, consumes" shape as
+    /// `MacroState::take_force_recovery_request`.
+    pub fn take_detected(&self) -> bool {
+        self.detected.swap(false, Ordering::SeqCst)
+    }
+}
+
+/// Fold incoming samples into `rolling`'s amplitude buckets, and once the
+/// window is as long as `reference`, compare the two and flag `detected` on
+/// a close-enough match, then clear the window so the same bite isn't
+/// matched twice.
+fn push_samples(
+    data: &[f32],
+    channels: usize,
+    bucket_len: usize,
+    threshold: f32,
+    rolling: &Arc<Mutex<RollingEnvelope>>,
+    reference: &[f32],
+    detected: &AtomicBool,
+) {
+    let mut rolling = rolling.lock();
+    for frame in data.chunks(channels) {
+        let mono = frame.iter().sum::<f32>() / frame.len().max(1) as f32;
+        rolling.bucket_sum += mono * mono;
+        rolling.bucket_count += 1;
+
+        if rolling.bucket_count < bucket_len {
+            continue;
+        }
+
+        let rms = (rolling.bucket_sum / rolling.bucket_count as f32).sqrt();
+        rolling.bucket_sum = 0.0;
+        rolling.bucket_count = 0;
+
+        rolling.window.push(rms);
+        if rolling.window.len() > reference.len() {
+            rolling.window.remove(0);
+        }
+
+        if rolling.window.len() == reference.len()
+            && envelope_distance(&rolling.window, reference) < threshold
+        {
+            detected.store(true, Ordering::SeqCst);
+            rolling.window.clear();
+        }
+    }
+}
+
+/// Normalized root-mean-square distance between two equal-length envelopes,
+/// scaled by the reference's own peak so quieter reference clips don't need
+/// a different threshold than louder ones.
+fn envelope_distance(window: &[f32], reference: &[f32]) -> f32 {
+    let reference_scale = reference.iter().cloned().fold(0f32, f32::max).max(f32::EPSILON);
+    let sum_sq: f32 = window
+        .iter()
+        .zip(reference.iter())
+        .map(|(w, r)| ((w - r) / reference_scale).powi(2))
+        .sum();
+    (sum_sq / window.len().max(1) as f32).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bite_detection_mode_from_setting_defaults_to_image() {
+        assert_eq!(BiteDetectionMode::from_setting("bogus"), BiteDetectionMode::Image);
+        assert_eq!(BiteDetectionMode::from_setting("audio"), BiteDetectionMode::Audio);
+        assert_eq!(BiteDetectionMode::from_setting("both"), BiteDetectionMode::Both);
+    }
+
+    #[test]
+    fn test_bite_detection_mode_uses_flags() {
+        assert!(BiteDetectionMode::Image.uses_image());
+        assert!(!BiteDetectionMode::Image.uses_audio());
+        assert!(BiteDetectionMode::Both.uses_image());
+        assert!(BiteDetectionMode::Both.uses_audio());
+    }
+
+    #[test]
+    fn test_envelope_distance_identical_envelopes_is_zero() {
+        let envelope = vec![0.1, 0.2, 0.3];
+        assert_eq!(envelope_distance(&envelope, &envelope), 0.0);
+    }
+}
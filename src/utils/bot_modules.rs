@@ -0,0 +1,147 @@
+//! Toggleable automation-module registry for the macro loop.
+//!
+//! The fishing loop used to bake every behavior - auto-recast, minigame lane
+//! following, no-progress recovery, fish-type detection - directly into
+//! `post_catch_loop`/`main_loop`, so there was no way to run a subset of them
+//! without editing the loop body. Each behavior is now a small `BotModule`
+//! registered here; `MacroState` asks the registry whether a given module is
+//! enabled at the same call sites the behavior already lived at, and drives
+//! `AntiAfkJitterModule` - the one module with no existing call site - via
+//! `tick_enabled` instead. Mirrors the one-source-of-truth registry shape
+//! `ui::commands::COMMANDS` uses for dashboard actions.
+
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+use crate::input;
+
+/// Read-only snapshot the loop hands to every module on each `tick_enabled`.
+pub struct LoopContext {
+    pub time_since_progress: Duration,
+}
+
+/// One independently toggleable piece of automation.
+pub trait BotModule: Send {
+    /// Stable key used for the enabled-map and the `set_module_enabled` IPC action.
+    fn name(&self) -> &'static str;
+
+    /// Run this module's own periodic behavior. Modules whose behavior is
+    /// gated inline in the loop (checked via `ModuleRegistry::is_enabled`
+    /// instead) leave this as a no-op.
+    fn tick(&mut self, _ctx: &LoopContext) {}
+}
+
+/// Auto-clicking the "continue" button after a successful catch.
+struct AutoRecastModule;
+impl BotModule for AutoRecastModule {
+    fn name(&self) -> &'static str {
+        "AutoRecast"
+    }
+}
+
+/// Following the minigame's left/right arrow prompts by holding the matching key.
+struct MinigameLaneSolverModule;
+impl BotModule for MinigameLaneSolverModule {
+    fn name(&self) -> &'static str {
+        "MinigameLaneSolver"
+    }
+}
+
+/// Pressing ESC/fish-key and restarting the session after `NO_PROGRESS_LIMIT`
+/// seconds without progress.
+struct NoProgressRecoveryModule;
+impl BotModule for NoProgressRecoveryModule {
+    fn name(&self) -> &'static str {
+        "NoProgressRecovery"
+    }
+}
+
+/// Matching the caught fish against `fish/` templates to record its type and XP.
+struct FishTypeDetectionModule;
+impl BotModule for FishTypeDetectionModule {
+    fn name(&self) -> &'static str {
+        "FishTypeDetection"
+    }
+}
+
+/// Periodically nudges the mouse by a pixel so a long idle session isn't
+/// flagged AFK by the client. The only module with no pre-existing call
+/// site, so it owns its own cadence and genuinely runs through `tick`.
+struct AntiAfkJitterModule {
+    interval: Duration,
+    last_jitter: Instant,
+}
+
+impl AntiAfkJitterModule {
+    fn new() -> Self {
+        Self {
+            interval: Duration::from_secs(90),
+            last_jitter: Instant::now(),
+        }
+    }
+}
+
+impl BotModule for AntiAfkJitterModule {
+    fn name(&self) -> &'static str {
+        "AntiAfkJitter"
+    }
+
+    fn tick(&mut self, _ctx: &LoopContext) {
+        if self.last_jitter.elapsed() < self.interval {
+            return;
+        }
+        input::mouse_jitter();
+        self.last_jitter = Instant::now();
+    }
+}
+
+/// All automation modules plus which of them are currently enabled.
+///
+/// Enablement is runtime-only, like `MacroState`'s `arrow_threshold`/
+/// `spam_cps` - it resets to "all enabled" on the next launch rather than
+/// being persisted to `config/settings.json`.
+pub struct ModuleRegistry {
+    modules: Vec<Box<dyn BotModule>>,
+    enabled: Vec<(&'static str, bool)>,
+}
+
+impl ModuleRegistry {
+    pub fn new() -> Self {
+        let modules: Vec<Box<dyn BotModule>> = vec![
+            Box::new(AutoRecastModule),
+            Box::new(MinigameLaneSolverModule),
+            Box::new(NoProgressRecoveryModule),
+            Box::new(FishTypeDetectionModule),
+            Box::new(AntiAfkJitterModule::new()),
+        ];
+        let enabled = modules.iter().map(|m| (m.name(), true)).collect();
+        Self { modules, enabled }
+    }
+
+    /// Whether `name` is enabled. Unknown names are treated as enabled, so a
+    /// call site that's misspelled its module name fails open rather than
+    /// silently disabling itself.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.enabled
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, e)| *e)
+            .unwrap_or(true)
+    }
+
+    pub fn set_enabled(&mut self, name: &str, value: bool) {
+        if let Some(entry) = self.enabled.iter_mut().find(|(n, _)| *n == name) {
+            entry.1 = value;
+        }
+    }
+
+    /// Run `tick` on every enabled module. Called once per main-loop iteration.
+    pub fn tick_enabled(&mut self, ctx: &LoopContext) {
+        for module in self.modules.iter_mut() {
+            if self.enabled.iter().any(|(n, e)| *n == module.name() && *e) {
+                module.tick(ctx);
+            }
+        }
+    }
+}
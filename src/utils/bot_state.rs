@@ -4,8 +4,128 @@
 
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
-use serde::Serialize;
-use std::sync::atomic::{AtomicBool, Ordering};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::fish::Fish;
+use crate::utils::discord;
+use crate::utils::event_bus;
+use crate::utils::notifications;
+use crate::utils::path::get_data_dir;
+use crate::utils::recorder::{EventKind, RECORDER};
+
+/// How many samples `sample_history` keeps before dropping the oldest, so the
+/// in-panel chart covers a bounded window instead of growing forever.
+const HISTORY_CAPACITY: usize = 120;
+
+/// One point on the in-panel rate-over-time chart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistorySample {
+    pub timestamp: String,
+    pub catches: i32,
+    pub misses: i32,
+    pub xp: i32,
+    pub rate: f64,
+}
+
+/// A completed session's sampled history, persisted so the "session log"
+/// view can list past sessions without keeping them all in memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHistory {
+    pub start: String,
+    pub end: String,
+    pub samples: Vec<HistorySample>,
+}
+
+fn session_history_path() -> std::path::PathBuf {
+    get_data_dir().join("logs").join("session_history.json")
+}
+
+/// Append a completed session's samples to `logs/session_history.json`.
+fn persist_session_history(entry: SessionHistory) {
+    let path = session_history_path();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let mut sessions: Vec<SessionHistory> = if path.exists() {
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    sessions.push(entry);
+
+    if let Ok(content) = serde_json::to_string_pretty(&sessions) {
+        let _ = fs::write(&path, content);
+    }
+}
+
+/// Load every persisted session history, oldest first.
+pub fn load_session_history() -> Vec<SessionHistory> {
+    let path = session_history_path();
+    if let Ok(content) = fs::read_to_string(&path) {
+        if let Ok(sessions) = serde_json::from_str(&content) {
+            return sessions;
+        }
+    }
+    Vec::new()
+}
+
+/// How many entries `SharedBotState`'s message log keeps before dropping the
+/// oldest, so a long-running session's log doesn't grow forever.
+const MESSAGE_LOG_CAPACITY: usize = 100;
+
+/// Severity of a `Message` in `SharedBotState`'s log, for the JS overlay's
+/// color-coded scrolling log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// One entry in `SharedBotState`'s bounded message log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub level: MessageLevel,
+    /// Monotonically increasing sequence number rather than a wall-clock
+    /// time, so the overlay can order entries precisely even when several
+    /// land in the same instant.
+    pub ts: u64,
+    pub text: String,
+}
+
+/// Source for `Message::ts` - a plain counter, not wall-clock time.
+static MESSAGE_SEQ: AtomicU64 = AtomicU64::new(0);
+
+impl Message {
+    fn new(level: MessageLevel, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            ts: MESSAGE_SEQ.fetch_add(1, Ordering::SeqCst),
+            text: text.into(),
+        }
+    }
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(MessageLevel::Info, text)
+    }
+
+    pub fn warn(text: impl Into<String>) -> Self {
+        Self::new(MessageLevel::Warning, text)
+    }
+
+    pub fn err(text: impl Into<String>) -> Self {
+        Self::new(MessageLevel::Error, text)
+    }
+}
 
 /// A detected region for visualization (ESP-like box)
 #[derive(Debug, Clone, Serialize)]
@@ -86,6 +206,30 @@ pub struct SharedStats {
     pub misses: i32,
     pub xp: i32,
     pub rate: f64,
+    /// Caught fish the keep/release policy discarded - see
+    /// `FishService::should_keep` and `SharedBotState::increment_released`.
+    pub released: i32,
+}
+
+/// Self-updater progress, surfaced to the dashboard as the status payload's
+/// `update` field so the panel can show a progress indicator without polling
+/// a separate endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateStatus {
+    /// "checking" | "available" | "downloading" | "uptodate" | "error"
+    pub state: String,
+    pub version: String,
+    pub progress: f32,
+}
+
+impl Default for UpdateStatus {
+    fn default() -> Self {
+        Self {
+            state: "uptodate".to_string(),
+            version: String::new(),
+            progress: 0.0,
+        }
+    }
 }
 
 /// Global shared bot state
@@ -98,6 +242,27 @@ pub struct SharedBotState {
     detection_boxes: RwLock<Vec<DetectionBox>>,
     /// Game window rectangle (x, y, width, height)
     game_window_rect: RwLock<Option<(i32, i32, i32, i32)>>,
+    /// Most recent frame-difference delta reported by `ScreenService`'s
+    /// change-detection capture, for display in the UI.
+    frame_delta: RwLock<u32>,
+    /// Catches so far, keyed by fish name.
+    catches_by_species: RwLock<HashMap<String, i32>>,
+    /// Catches so far, keyed by rarity label (e.g. "Common", "Mythical").
+    catches_by_rarity: RwLock<HashMap<String, i32>>,
+    /// Rate-over-time samples for the current session, oldest first, bounded
+    /// to `HISTORY_CAPACITY`.
+    history: RwLock<VecDeque<HistorySample>>,
+    /// When the current session started, set by `set_running(true)` and
+    /// consumed by `set_running(false)` to persist `SessionHistory`.
+    session_start: RwLock<Option<String>>,
+    /// Self-updater progress, driven by the `check_update` dashboard action.
+    update_status: RwLock<UpdateStatus>,
+    /// RFC3339 timestamp of the last `emit_status` call, for `TelemetryServer`'s
+    /// conditional-polling support.
+    updated_at: RwLock<String>,
+    /// Bounded severity-tagged message log backing `OverviewApi::get_messages`,
+    /// bounded to `MESSAGE_LOG_CAPACITY`.
+    messages: RwLock<VecDeque<Message>>,
 }
 
 impl SharedBotState {
@@ -109,6 +274,14 @@ impl SharedBotState {
             detail_message: RwLock::new(String::new()),
             detection_boxes: RwLock::new(Vec::new()),
             game_window_rect: RwLock::new(None),
+            frame_delta: RwLock::new(0),
+            catches_by_species: RwLock::new(HashMap::new()),
+            catches_by_rarity: RwLock::new(HashMap::new()),
+            history: RwLock::new(VecDeque::new()),
+            session_start: RwLock::new(None),
+            update_status: RwLock::new(UpdateStatus::default()),
+            updated_at: RwLock::new(chrono::Utc::now().to_rfc3339()),
+            messages: RwLock::new(VecDeque::new()),
         }
     }
 
@@ -117,16 +290,95 @@ impl SharedBotState {
         self.running.load(Ordering::SeqCst)
     }
 
-    /// Set bot running state
+    /// Set bot running state. Starting a session clears the previous
+    /// session's history and records its start time; stopping persists the
+    /// collected samples to `logs/session_history.json`.
     pub fn set_running(&self, running: bool) {
-        self.running.store(running, Ordering::SeqCst);
+        let changed = self.running.swap(running, Ordering::SeqCst) != running;
         if running {
             self.set_activity(BotActivity::WaitingForDefaultScreen);
+            if changed {
+                self.history.write().clear();
+                *self.session_start.write() = Some(chrono::Utc::now().to_rfc3339());
+            }
         } else {
             self.set_activity(BotActivity::Stopped);
+            if changed {
+                let taken_start = self.session_start.write().take();
+                if let Some(start) = taken_start {
+                    let samples: Vec<HistorySample> = self.history.read().iter().cloned().collect();
+                    if !samples.is_empty() {
+                        persist_session_history(SessionHistory {
+                            start: start.clone(),
+                            end: chrono::Utc::now().to_rfc3339(),
+                            samples,
+                        });
+                    }
+
+                    let duration_seconds = chrono::DateTime::parse_from_rfc3339(&start)
+                        .map(|started| (chrono::Utc::now() - started.with_timezone(&chrono::Utc)).num_seconds())
+                        .unwrap_or(0);
+                    let stats = self.get_stats();
+                    if let Some(result) = discord::report_session(
+                        stats.catches,
+                        stats.misses,
+                        stats.xp,
+                        stats.rate,
+                        duration_seconds,
+                    ) {
+                        self.set_detail_message(result);
+                    }
+                }
+            }
+        }
+        if changed {
+            self.emit_status();
         }
     }
 
+    /// Append the current stats as a new history sample, bounded to
+    /// `HISTORY_CAPACITY`, and push the update so the chart can redraw even
+    /// if the underlying stats haven't changed since the last sample.
+    pub fn sample_history(&self) {
+        let stats = self.get_stats();
+        let sample = HistorySample {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            catches: stats.catches,
+            misses: stats.misses,
+            xp: stats.xp,
+            rate: stats.rate,
+        };
+
+        let mut history = self.history.write();
+        history.push_back(sample);
+        while history.len() > HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        drop(history);
+
+        self.emit_status();
+    }
+
+    /// Get the current session's sampled history, oldest first.
+    pub fn get_history(&self) -> Vec<HistorySample> {
+        self.history.read().iter().cloned().collect()
+    }
+
+    /// Update the self-updater's reported state and push it to the dashboard.
+    pub fn set_update_status(&self, state: &str, version: &str, progress: f32) {
+        *self.update_status.write() = UpdateStatus {
+            state: state.to_string(),
+            version: version.to_string(),
+            progress,
+        };
+        self.emit_status();
+    }
+
+    /// Get the self-updater's current status.
+    pub fn get_update_status(&self) -> UpdateStatus {
+        self.update_status.read().clone()
+    }
+
     /// Get current activity
     pub fn get_activity(&self) -> BotActivity {
         self.activity.read().clone()
@@ -134,7 +386,14 @@ impl SharedBotState {
 
     /// Set current activity
     pub fn set_activity(&self, activity: BotActivity) {
+        RECORDER.record(EventKind::Activity {
+            label: activity.description().to_string(),
+        });
+        let changed = *self.activity.read() != activity;
         *self.activity.write() = activity;
+        if changed {
+            self.emit_status();
+        }
     }
 
     /// Get detailed message
@@ -144,7 +403,38 @@ impl SharedBotState {
 
     /// Set detailed message
     pub fn set_detail_message(&self, message: impl Into<String>) {
-        *self.detail_message.write() = message.into();
+        let message = message.into();
+        let changed = *self.detail_message.read() != message;
+        *self.detail_message.write() = message.clone();
+        self.append_message(Message::info(message));
+        if changed {
+            self.emit_status();
+        }
+    }
+
+    /// Append `message` to the bounded log, dropping the oldest entry past
+    /// `MESSAGE_LOG_CAPACITY`. Shared by `set_detail_message` and
+    /// `push_message` so both go through the same cap.
+    fn append_message(&self, message: Message) {
+        let mut messages = self.messages.write();
+        messages.push_back(message);
+        while messages.len() > MESSAGE_LOG_CAPACITY {
+            messages.pop_front();
+        }
+    }
+
+    /// Report a message to the log without touching `detail_message` or the
+    /// current activity, so internal subsystems can surface a warning/error
+    /// (e.g. a failed window grab) without stomping whatever the status line
+    /// is currently showing.
+    pub fn push_message(&self, message: Message) {
+        self.append_message(message);
+        self.emit_status();
+    }
+
+    /// Get every message currently retained in the log, oldest first.
+    pub fn get_messages(&self) -> Vec<Message> {
+        self.messages.read().iter().cloned().collect()
     }
 
     /// Get current stats
@@ -155,6 +445,10 @@ impl SharedBotState {
     /// Update stats
     pub fn update_stats(&self, catches: i32, misses: i32, xp: i32) {
         let mut stats = self.stats.write();
+        let changed = stats.catches != catches || stats.misses != misses || stats.xp != xp;
+        let previous_xp = stats.xp;
+        let previous_catches = stats.catches;
+        let previous_misses = stats.misses;
         stats.catches = catches;
         stats.misses = misses;
         stats.xp = xp;
@@ -164,23 +458,63 @@ impl SharedBotState {
         } else {
             0.0
         };
+        drop(stats);
+        if changed {
+            if catches > previous_catches {
+                RECORDER.record(EventKind::Catch { xp_gain: xp - previous_xp });
+            }
+            if misses > previous_misses {
+                RECORDER.record(EventKind::Miss);
+            }
+            notifications::maybe_notify_level_up(previous_xp, xp);
+            self.emit_status();
+        }
     }
 
-    /// Increment catches
-    pub fn increment_catch(&self, xp_gain: i32) {
-        let mut stats = self.stats.write();
-        stats.catches += 1;
-        stats.xp += xp_gain;
-        let total = stats.catches + stats.misses;
-        stats.rate = if total > 0 {
-            (stats.catches as f64 / total as f64) * 100.0
-        } else {
-            0.0
-        };
+    /// Record a catch's species/rarity in the breakdown maps without touching
+    /// the running totals. Exposed separately so callers that track totals
+    /// through another path (e.g. a session-local stats struct) can still keep
+    /// the breakdown in sync. Emits a `catch-logged` event when a fish is
+    /// attributed, so the dashboard can update its catch table in real time.
+    pub fn record_catch_breakdown(&self, fish: Option<&Fish>) {
+        if let Some(fish) = fish {
+            *self
+                .catches_by_species
+                .write()
+                .entry(fish.name.clone())
+                .or_insert(0) += 1;
+            *self
+                .catches_by_rarity
+                .write()
+                .entry(fish.rarity.value().to_string())
+                .or_insert(0) += 1;
+
+            event_bus::emit(
+                "catch-logged",
+                serde_json::json!({
+                    "name": fish.name,
+                    "rarity": fish.rarity.value(),
+                    "xp": fish.xp,
+                }),
+            );
+
+            notifications::maybe_notify_catch(fish);
+        }
+    }
+
+    /// Get catches broken down by fish species
+    pub fn get_catches_by_species(&self) -> HashMap<String, i32> {
+        self.catches_by_species.read().clone()
+    }
+
+    /// Get catches broken down by rarity tier
+    pub fn get_catches_by_rarity(&self) -> HashMap<String, i32> {
+        self.catches_by_rarity.read().clone()
     }
 
     /// Increment misses
     pub fn increment_miss(&self) {
+        RECORDER.record(EventKind::Miss);
         let mut stats = self.stats.write();
         stats.misses += 1;
         let total = stats.catches + stats.misses;
@@ -189,11 +523,25 @@ impl SharedBotState {
         } else {
             0.0
         };
+        drop(stats);
+        self.emit_status();
+    }
+
+    /// Record a catch the keep/release policy discarded. Doesn't touch
+    /// `catches`/`rate` - the catch itself was already counted there; this is
+    /// purely a "how many of those did I let go" tally.
+    pub fn increment_released(&self) {
+        let mut stats = self.stats.write();
+        stats.released += 1;
+        drop(stats);
+        self.emit_status();
     }
 
     /// Reset stats for new session
     pub fn reset_stats(&self) {
         *self.stats.write() = SharedStats::default();
+        self.catches_by_species.write().clear();
+        self.catches_by_rarity.write().clear();
     }
 
     /// Add a detection box for visualization
@@ -211,8 +559,11 @@ impl SharedBotState {
         self.detection_boxes.read().clone()
     }
 
-    /// Set detection boxes (replaces all)
+    /// Set detection boxes (replaces all), recording a snapshot for replay
     pub fn set_detection_boxes(&self, boxes: Vec<DetectionBox>) {
+        RECORDER.record(EventKind::Detection {
+            boxes: boxes.clone(),
+        });
         *self.detection_boxes.write() = boxes;
     }
 
@@ -226,8 +577,20 @@ impl SharedBotState {
         *self.game_window_rect.read()
     }
 
-    /// Get status as JSON string for UI
-    pub fn to_json(&self) -> String {
+    /// Set the most recent frame-difference delta from change-detection capture
+    pub fn set_frame_delta(&self, delta: u32) {
+        *self.frame_delta.write() = delta;
+    }
+
+    /// Get the most recent frame-difference delta
+    pub fn get_frame_delta(&self) -> u32 {
+        *self.frame_delta.read()
+    }
+
+    /// Build the status snapshot shared by `to_json`, the `bot-status`
+    /// event payload, and the remote-control server's snapshot frames, so
+    /// none of them can drift apart.
+    pub(crate) fn status_value(&self) -> serde_json::Value {
         let stats = self.get_stats();
         let activity = self.get_activity();
         let detail = self.get_detail_message();
@@ -240,10 +603,39 @@ impl SharedBotState {
                 "catches": stats.catches,
                 "misses": stats.misses,
                 "xp": stats.xp,
-                "rate": format!("{:.2}", stats.rate)
-            }
+                "rate": format!("{:.2}", stats.rate),
+                "released": stats.released
+            },
+            "frame_delta": self.get_frame_delta(),
+            "catch_breakdown": {
+                "by_species": self.get_catches_by_species(),
+                "by_rarity": self.get_catches_by_rarity()
+            },
+            "history": self.get_history(),
+            "update": self.get_update_status(),
+            "updated_at": self.updated_at()
         })
-        .to_string()
+    }
+
+    /// Push the current status snapshot onto the event bus as a `bot-status`
+    /// event. Most setters call this only when the value they changed
+    /// actually differs from before; `sample_history`, `set_update_status`
+    /// and `push_message` call it on every call.
+    fn emit_status(&self) {
+        *self.updated_at.write() = chrono::Utc::now().to_rfc3339();
+        event_bus::emit("bot-status", self.status_value());
+    }
+
+    /// RFC3339 timestamp of the last change, bumped by every `emit_status`
+    /// call. `TelemetryServer` compares a polling client's last-seen value
+    /// against this to decide whether anything changed since.
+    pub fn updated_at(&self) -> String {
+        self.updated_at.read().clone()
+    }
+
+    /// Get status as JSON string for UI
+    pub fn to_json(&self) -> String {
+        self.status_value().to_string()
     }
 
     /// Get detection boxes as JSON string for ESP overlay
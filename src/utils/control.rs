@@ -0,0 +1,59 @@
+//! Runtime reconfiguration channel for the running macro loop.
+//!
+//! Detection threshold, click rate, and the no-progress timeout used to be
+//! baked-in constants the macro loop read directly, so changing any of them
+//! meant stopping and restarting the bot. The UI now pushes a
+//! `ThreadControlEvent` here instead, and `MacroState` drains it once per
+//! loop iteration and applies it to its own live fields - the same
+//! global-channel shape `event_bus` uses for broadcast, just point-to-point
+//! since only one macro loop ever drains it.
+
+#![allow(dead_code)]
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// A runtime setting change to apply to the live macro loop.
+#[derive(Debug, Clone)]
+pub enum ThreadControlEvent {
+    UpdateArrowThreshold(f32),
+    UpdateSpamCps(u32),
+    UpdateNoProgressLimit(u64),
+    ResetStats,
+    RebindKeys,
+    ToggleProfiling(bool),
+    SetModuleEnabled(String, bool),
+    TogglePause,
+    RequestForceRecovery,
+}
+
+struct ControlChannel {
+    tx: Sender<ThreadControlEvent>,
+    rx: Mutex<Option<Receiver<ThreadControlEvent>>>,
+}
+
+static CHANNEL: Lazy<ControlChannel> = Lazy::new(|| {
+    let (tx, rx) = mpsc::channel();
+    ControlChannel {
+        tx,
+        rx: Mutex::new(Some(rx)),
+    }
+});
+
+/// Push a control event to the running macro loop. Silently dropped if
+/// nothing has taken the receiver yet (the bot hasn't started).
+pub fn send(event: ThreadControlEvent) {
+    let _ = CHANNEL.tx.send(event);
+}
+
+/// Take ownership of the receiver - called once, by `MacroState::new()`, so
+/// only the macro loop ever drains events.
+pub fn take_receiver() -> Receiver<ThreadControlEvent> {
+    CHANNEL
+        .rx
+        .lock()
+        .take()
+        .expect("control channel receiver already taken")
+}
@@ -0,0 +1,90 @@
+//! Discord webhook reporting for session summaries.
+//!
+//! Sends a formatted embed with the session's catch stats to a user-supplied
+//! Discord webhook URL when a fishing session stops. The URL lives in the
+//! same settings file as the dashboard's other preferences - there's no
+//! dedicated config section for this, just one more key `StatsApi` reads and
+//! writes the usual way.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+
+use crate::utils::path::get_data_dir;
+
+fn webhook_url() -> Option<String> {
+    let settings_file = get_data_dir().join("config").join("settings.json");
+    let content = fs::read_to_string(&settings_file).ok()?;
+    let settings: HashMap<String, String> = serde_json::from_str(&content).ok()?;
+    settings
+        .get("discord_webhook_url")
+        .cloned()
+        .filter(|s| !s.is_empty())
+}
+
+fn format_duration(total_seconds: i64) -> String {
+    let total_seconds = total_seconds.max(0);
+    let h = total_seconds / 3600;
+    let m = (total_seconds % 3600) / 60;
+    let s = total_seconds % 60;
+    format!("{:02}:{:02}:{:02}", h, m, s)
+}
+
+fn session_embed(catches: i32, misses: i32, xp: i32, rate: f64, duration_seconds: i64) -> serde_json::Value {
+    serde_json::json!({
+        "embeds": [{
+            "title": "Fishing session complete",
+            "color": 0x38c6ff,
+            "fields": [
+                { "name": "Catches", "value": catches.to_string(), "inline": true },
+                { "name": "Misses", "value": misses.to_string(), "inline": true },
+                { "name": "Rate", "value": format!("{:.1}%", rate), "inline": true },
+                { "name": "XP", "value": xp.to_string(), "inline": true },
+                { "name": "Duration", "value": format_duration(duration_seconds), "inline": true }
+            ]
+        }]
+    })
+}
+
+fn post_webhook(url: &str, payload: &serde_json::Value) -> String {
+    let client = reqwest::blocking::Client::new();
+    match client
+        .post(url)
+        .json(payload)
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+    {
+        Ok(response) if response.status().is_success() => "Discord webhook sent".to_string(),
+        Ok(response) => format!("Discord webhook failed: HTTP {}", response.status()),
+        Err(e) => format!("Discord webhook failed: {}", e),
+    }
+}
+
+/// POST a session summary embed to the configured webhook, if one is set.
+/// Returns a short status message for the `.activity` line, or `None` when
+/// no webhook is configured - there's nothing to report, and nothing to show.
+pub fn report_session(catches: i32, misses: i32, xp: i32, rate: f64, duration_seconds: i64) -> Option<String> {
+    let url = webhook_url()?;
+    let payload = session_embed(catches, misses, xp, rate, duration_seconds);
+    Some(post_webhook(&url, &payload))
+}
+
+/// Send a minimal test message to the given URL, for the dashboard's "Test
+/// webhook" button - doesn't read the persisted URL, since the user may be
+/// testing a value they haven't saved yet.
+pub fn test_webhook(url: &str) -> String {
+    let payload = serde_json::json!({ "content": "Blue Mancing: webhook test OK" });
+    post_webhook(url, &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration_pads_components() {
+        assert_eq!(format_duration(0), "00:00:00");
+        assert_eq!(format_duration(3661), "01:01:01");
+    }
+}
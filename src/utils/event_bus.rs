@@ -0,0 +1,56 @@
+//! Lightweight pub/sub event bus for pushing UI events out of platform-
+//! agnostic code without depending on any windowing toolkit.
+//!
+//! `bot_state.rs` has no `tao`/`wry` types in scope and must stay buildable
+//! on every platform and feature combination, while the consumers that can
+//! actually act on these events - the `gui`+`windows` event loop in
+//! `ui_service.rs`, the remote WebSocket broadcaster in
+//! `net::remote_control` - live behind their own cfgs or run conditionally.
+//! A broadcast registry decouples the two: setters call `emit` whenever
+//! something actually changes, and each consumer calls `subscribe` once to
+//! get its own independent receiver fed a clone of every event from then on.
+
+#![allow(dead_code)]
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// A named event with a JSON payload, meant to be dispatched to a webview as
+/// `window.dispatchEvent(new CustomEvent(name, { detail: payload }))`, or
+/// broadcast to remote clients as-is.
+#[derive(Debug, Clone)]
+pub struct BotEvent {
+    pub name: String,
+    pub payload: serde_json::Value,
+}
+
+struct EventBus {
+    subscribers: Mutex<Vec<Sender<BotEvent>>>,
+}
+
+static EVENT_BUS: Lazy<EventBus> = Lazy::new(|| EventBus {
+    subscribers: Mutex::new(Vec::new()),
+});
+
+/// Push a named event to every current subscriber. A no-op if nobody has
+/// subscribed yet (headless builds, or before any consumer has started) -
+/// callers never need to check whether anything is listening.
+pub fn emit(name: &str, payload: serde_json::Value) {
+    let event = BotEvent {
+        name: name.to_string(),
+        payload,
+    };
+    let mut subscribers = EVENT_BUS.subscribers.lock();
+    subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+}
+
+/// Subscribe to future events. Each call gets its own independent receiver
+/// fed a clone of every event emitted from then on, so e.g. the GUI event
+/// loop and a remote broadcaster can both listen without racing to drain a
+/// single shared channel.
+pub fn subscribe() -> Receiver<BotEvent> {
+    let (sender, receiver) = channel();
+    EVENT_BUS.subscribers.lock().push(sender);
+    receiver
+}
@@ -0,0 +1,64 @@
+//! Event-driven core for `main_loop`, replacing its blind 100ms poll.
+//!
+//! The hotkey thread used to call `handle_start_key`/`handle_stop_key`
+//! directly from its own thread, and `main_loop` otherwise just slept a
+//! fixed 100ms and re-read `SHARED_STATE` every iteration to notice a
+//! UI-triggered start/stop. `main_loop` now selects on this `mpsc` channel
+//! with `recv_timeout` instead - the timeout keeps its existing
+//! image-polling cadence, but a posted event wakes it immediately. The
+//! hotkey thread posts `BotEvent`s here rather than touching
+//! `MacroState`/`SHARED_STATE` from another thread, mirroring the
+//! point-to-point shape `utils::control` already uses for runtime settings.
+//!
+//! The UI/telemetry/remote-control surfaces that flip `SHARED_STATE`'s
+//! running flag directly (see `net::status_server`, `net::remote_control`,
+//! `ui::ui_service`, `ui::overview_api`) aren't routed through this channel
+//! yet - `main_loop` keeps its old reconciliation check as a fallback for
+//! those, so this is additive rather than a wholesale replacement.
+
+#![allow(dead_code)]
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// An event posted to the running macro loop.
+#[derive(Debug, Clone)]
+pub enum BotEvent {
+    StartRequested,
+    StopRequested,
+    ConfigUpdated,
+    WindowLost,
+    ProgressTick,
+    Shutdown,
+}
+
+struct EventChannel {
+    tx: Sender<BotEvent>,
+    rx: Mutex<Option<Receiver<BotEvent>>>,
+}
+
+static CHANNEL: Lazy<EventChannel> = Lazy::new(|| {
+    let (tx, rx) = mpsc::channel();
+    EventChannel {
+        tx,
+        rx: Mutex::new(Some(rx)),
+    }
+});
+
+/// Post an event to the running macro loop. Silently dropped if nothing has
+/// taken the receiver yet (the bot hasn't started).
+pub fn send(event: BotEvent) {
+    let _ = CHANNEL.tx.send(event);
+}
+
+/// Take ownership of the receiver - called once, by `MacroState::new()`, so
+/// only the macro loop ever drains it.
+pub fn take_receiver() -> Receiver<BotEvent> {
+    CHANNEL
+        .rx
+        .lock()
+        .take()
+        .expect("event channel receiver already taken")
+}
@@ -0,0 +1,147 @@
+//! Declarative keybind config mapping logical fishing actions to physical
+//! keys or chords, independent of the raw `input` layer.
+//!
+//! Bindings are read from `keybinds.ron` under the data dir's `config`
+//! folder; a missing file, unparsable RON, or an entry that fails
+//! `input::is_valid_key_spec` falls back to the corresponding built-in
+//! default rather than aborting the whole load.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+
+use crate::input::is_valid_key_spec;
+use crate::utils::path::get_data_dir;
+
+/// Logical fishing actions the bot's main loop performs, decoupled from the
+/// physical key that triggers them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FishingAction {
+    Cast,
+    Reel,
+    Confirm,
+    FocusWindow,
+}
+
+impl FishingAction {
+    const ALL: [FishingAction; 4] = [
+        FishingAction::Cast,
+        FishingAction::Reel,
+        FishingAction::Confirm,
+        FishingAction::FocusWindow,
+    ];
+
+    fn default_key(self) -> &'static str {
+        match self {
+            FishingAction::Cast => "F",
+            FishingAction::Reel => "SPACE",
+            FishingAction::Confirm => "ENTER",
+            FishingAction::FocusWindow => "ALT+TAB",
+        }
+    }
+}
+
+/// Path to the logical-action keybind config file.
+fn keybinds_path() -> PathBuf {
+    get_data_dir().join("config").join("keybinds.ron")
+}
+
+/// Resolved action -> key/chord bindings for the fishing loop.
+#[derive(Debug, Clone)]
+pub struct Keybinds {
+    bindings: HashMap<FishingAction, String>,
+}
+
+impl Keybinds {
+    fn defaults() -> Self {
+        let bindings = FishingAction::ALL
+            .iter()
+            .map(|a| (*a, a.default_key().to_string()))
+            .collect();
+        Self { bindings }
+    }
+
+    /// Load `keybinds.ron`, validating each entry and keeping the built-in
+    /// default for anything missing, unparsable, or unresolvable.
+    pub fn load() -> Self {
+        let mut keybinds = Self::defaults();
+
+        let path = keybinds_path();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => return keybinds,
+        };
+
+        let overrides: HashMap<FishingAction, String> = match ron::from_str(&content) {
+            Ok(map) => map,
+            Err(e) => {
+                tracing::warn!("[KEYBINDS] Failed to parse {}: {}", path.display(), e);
+                return keybinds;
+            }
+        };
+
+        for (action, key_spec) in overrides {
+            if is_valid_key_spec(&key_spec) {
+                keybinds.bindings.insert(action, key_spec);
+            } else {
+                tracing::warn!(
+                    "[KEYBINDS] Invalid binding '{}' for {:?}, keeping default",
+                    key_spec,
+                    action
+                );
+            }
+        }
+
+        keybinds
+    }
+
+    /// Get the key/chord string bound to `action`.
+    pub fn get(&self, action: FishingAction) -> &str {
+        &self.bindings[&action]
+    }
+}
+
+impl Default for Keybinds {
+    fn default() -> Self {
+        Self::defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_cover_every_action() {
+        let keybinds = Keybinds::default();
+        for action in FishingAction::ALL {
+            assert!(!keybinds.get(action).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_invalid_override_keeps_default() {
+        let mut keybinds = Keybinds::defaults();
+        let default_cast = keybinds.get(FishingAction::Cast).to_string();
+
+        let key_spec = "NOT_A_REAL_KEY";
+        if is_valid_key_spec(key_spec) {
+            keybinds.bindings.insert(FishingAction::Cast, key_spec.to_string());
+        }
+
+        assert_eq!(keybinds.get(FishingAction::Cast), default_cast);
+    }
+
+    #[test]
+    fn test_valid_override_replaces_default() {
+        let mut keybinds = Keybinds::defaults();
+        let key_spec = "CTRL+R";
+        assert!(is_valid_key_spec(key_spec));
+        keybinds.bindings.insert(FishingAction::Reel, key_spec.to_string());
+        assert_eq!(keybinds.get(FishingAction::Reel), "CTRL+R");
+    }
+}
@@ -0,0 +1,229 @@
+//! Native key-capture for the settings UI's "click to rebind" flow.
+//!
+//! On Windows, installs a low-level keyboard hook (`WH_KEYBOARD_LL`) on a
+//! dedicated thread, enters a one-shot capture mode, and waits for the next
+//! non-modifier keypress. The captured keystroke is swallowed (never passed
+//! to `CallNextHookEx`) so it doesn't leak to the game, Esc cancels without
+//! changing the existing binding, and the whole thing times out after a few
+//! seconds if nothing is pressed.
+
+#![allow(dead_code)]
+
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::utils::keybinds;
+
+/// How long to wait for a keypress before giving up on a capture attempt.
+const CAPTURE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of a [`capture_key_for`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureOutcome {
+    /// A non-modifier key was captured, translated, and persisted under
+    /// `name`.
+    Captured(String),
+    /// Esc was pressed, or the pressed key has no key name; the previous
+    /// binding is unchanged.
+    Cancelled,
+    /// No key arrived before the timeout; the previous binding is unchanged.
+    TimedOut,
+}
+
+#[cfg(windows)]
+mod win {
+    use super::*;
+    use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        VK_CONTROL, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_RCONTROL, VK_RMENU,
+        VK_RSHIFT, VK_RWIN, VK_SHIFT,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+        TranslateMessage, UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, MSG, WH_KEYBOARD_LL,
+        WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+    };
+
+    /// What the hook callback observed, sent to the waiting capture call.
+    enum HookEvent {
+        Key(u32),
+        Cancelled,
+    }
+
+    /// Set only while a capture is in flight; the hook callback ignores
+    /// keystrokes entirely (and lets them through) otherwise.
+    static SENDER: Lazy<Mutex<Option<Sender<HookEvent>>>> = Lazy::new(|| Mutex::new(None));
+
+    fn is_modifier_vk(vk: u32) -> bool {
+        vk == VK_SHIFT.0 as u32
+            || vk == VK_LSHIFT.0 as u32
+            || vk == VK_RSHIFT.0 as u32
+            || vk == VK_CONTROL.0 as u32
+            || vk == VK_LCONTROL.0 as u32
+            || vk == VK_RCONTROL.0 as u32
+            || vk == VK_MENU.0 as u32
+            || vk == VK_LMENU.0 as u32
+            || vk == VK_RMENU.0 as u32
+            || vk == VK_LWIN.0 as u32
+            || vk == VK_RWIN.0 as u32
+    }
+
+    /// Translate a virtual-key code to the key-name string used throughout
+    /// `keybinds` (matches the inverse of `keybinds::string_to_code`).
+    fn vk_to_key_name(vk: u32) -> Option<String> {
+        // VK codes for '0'-'9' and 'A'-'Z' are the same as their ASCII values.
+        if (0x30..=0x39).contains(&vk) || (0x41..=0x5A).contains(&vk) {
+            return Some((vk as u8 as char).to_string());
+        }
+
+        let name = match vk {
+            0x70 => "F1",
+            0x71 => "F2",
+            0x72 => "F3",
+            0x73 => "F4",
+            0x74 => "F5",
+            0x75 => "F6",
+            0x76 => "F7",
+            0x77 => "F8",
+            0x78 => "F9",
+            0x79 => "F10",
+            0x7A => "F11",
+            0x7B => "F12",
+            0x1B => "ESC",
+            0x0D => "ENTER",
+            0x20 => "SPACE",
+            0x09 => "TAB",
+            0x08 => "BACKSPACE",
+            0x26 => "UP",
+            0x28 => "DOWN",
+            0x25 => "LEFT",
+            0x27 => "RIGHT",
+            _ => return None,
+        };
+        Some(name.to_string())
+    }
+
+    unsafe extern "system" fn keyboard_hook_proc(
+        code: i32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if code >= 0 {
+            let msg = wparam.0 as u32;
+            if msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN {
+                let kb = *(lparam.0 as *const KBDLLHOOKSTRUCT);
+                let mut sender = SENDER.lock();
+                if let Some(tx) = sender.as_ref() {
+                    if kb.vkCode == 0x1B {
+                        // Esc cancels; swallow it so it doesn't also reach the game.
+                        let _ = tx.send(HookEvent::Cancelled);
+                        sender.take();
+                        return LRESULT(1);
+                    }
+                    if !is_modifier_vk(kb.vkCode) {
+                        let _ = tx.send(HookEvent::Key(kb.vkCode));
+                        sender.take();
+                        return LRESULT(1);
+                    }
+                    // A bare modifier keydown doesn't end the capture, but is
+                    // still swallowed so it can't leak into the game either.
+                    return LRESULT(1);
+                }
+            }
+        }
+
+        CallNextHookEx(None, code, wparam, lparam)
+    }
+
+    /// A running hook thread, identified so it can be told to stop.
+    struct HookThread {
+        thread_id: u32,
+        join: std::thread::JoinHandle<()>,
+    }
+
+    fn spawn_hook_thread(tx: Sender<HookEvent>) -> HookThread {
+        let (ready_tx, ready_rx) = channel::<u32>();
+
+        let join = std::thread::spawn(move || unsafe {
+            *SENDER.lock() = Some(tx);
+
+            let hook = match SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_hook_proc), None, 0) {
+                Ok(hook) => hook,
+                Err(e) => {
+                    tracing::warn!("[KEYCAP] Failed to install keyboard hook: {:?}", e);
+                    let _ = ready_tx.send(0);
+                    return;
+                }
+            };
+
+            let _ = ready_tx.send(windows::Win32::System::Threading::GetCurrentThreadId());
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            SENDER.lock().take();
+            let _ = UnhookWindowsHookEx(hook);
+        });
+
+        let thread_id = ready_rx.recv().unwrap_or(0);
+        HookThread { thread_id, join }
+    }
+
+    fn stop_hook_thread(handle: HookThread) {
+        if handle.thread_id != 0 {
+            unsafe {
+                let _ = PostThreadMessageW(handle.thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+            }
+        }
+        let _ = handle.join.join();
+    }
+
+    pub fn capture_key_for(name: &str) -> super::CaptureOutcome {
+        let (tx, rx) = channel();
+        let hook_thread = spawn_hook_thread(tx);
+
+        let outcome = match rx.recv_timeout(super::CAPTURE_TIMEOUT) {
+            Ok(HookEvent::Key(vk)) => match vk_to_key_name(vk) {
+                Some(key_name) => {
+                    if let Err(e) = keybinds::set_key(name, &key_name) {
+                        tracing::warn!(
+                            "[KEYCAP] Failed to persist captured key '{}' for '{}': {}",
+                            key_name,
+                            name,
+                            e
+                        );
+                    }
+                    super::CaptureOutcome::Captured(key_name)
+                }
+                None => super::CaptureOutcome::Cancelled,
+            },
+            Ok(HookEvent::Cancelled) => super::CaptureOutcome::Cancelled,
+            Err(RecvTimeoutError::Timeout) => super::CaptureOutcome::TimedOut,
+            Err(RecvTimeoutError::Disconnected) => super::CaptureOutcome::Cancelled,
+        };
+
+        stop_hook_thread(hook_thread);
+        outcome
+    }
+}
+
+/// Enter capture mode for `name` and block (up to a few seconds) for the
+/// next non-modifier keypress, persisting it as `name`'s binding on success.
+#[cfg(windows)]
+pub fn capture_key_for(name: &str) -> CaptureOutcome {
+    win::capture_key_for(name)
+}
+
+/// Native key capture needs the Win32 low-level keyboard hook; there's no
+/// equivalent wired up for other platforms yet.
+#[cfg(not(windows))]
+pub fn capture_key_for(_name: &str) -> CaptureOutcome {
+    tracing::warn!("[KEYCAP] Native key capture is only implemented on Windows");
+    CaptureOutcome::TimedOut
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::sync::{Arc, RwLock};
 use once_cell::sync::Lazy;
-use global_hotkey::hotkey::Code;
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
 
 use crate::utils::path::get_data_dir;
 
@@ -21,9 +21,85 @@ pub static DEFAULT_KEYS: Lazy<HashMap<String, String>> = Lazy::new(|| {
     m.insert("esc_key".to_string(), "ESC".to_string());
     m.insert("left_key".to_string(), "A".to_string());
     m.insert("right_key".to_string(), "D".to_string());
+    m.insert("discard_key".to_string(), "R".to_string());
+    m.insert("pause_key".to_string(), "F8".to_string());
+    m.insert("recovery_key".to_string(), "F7".to_string());
+    m.insert("reload_config_key".to_string(), "F6".to_string());
+    m.insert("overlay_key".to_string(), "F11".to_string());
     m
 });
 
+/// An action a registered global hotkey can trigger, bound to a config key
+/// name via `ACTION_BINDINGS`. `main()` registers one `HotKey` per bound
+/// action and the hotkey listener thread dispatches on whichever one fired,
+/// instead of hardcoding only start/stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Start,
+    Stop,
+    TogglePause,
+    ForceRecovery,
+    ReloadConfig,
+    ToggleOverlay,
+}
+
+/// Which config key name binds to which `Action`. Add a new action here and
+/// a matching default in `DEFAULT_KEYS` to expose another hotkey.
+pub const ACTION_BINDINGS: &[(&str, Action)] = &[
+    ("start_key", Action::Start),
+    ("stop_key", Action::Stop),
+    ("pause_key", Action::TogglePause),
+    ("recovery_key", Action::ForceRecovery),
+    ("reload_config_key", Action::ReloadConfig),
+    ("overlay_key", Action::ToggleOverlay),
+];
+
+impl Action {
+    /// The name this action is addressed by in `OverviewApi::get_bindings`/
+    /// `set_binding`, e.g. for an editable keybind table in the overlay.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Start => "Start",
+            Action::Stop => "Stop",
+            Action::TogglePause => "PauseResume",
+            Action::ForceRecovery => "ForceRecovery",
+            Action::ReloadConfig => "ReloadConfig",
+            Action::ToggleOverlay => "ToggleOverlay",
+        }
+    }
+
+    /// The reverse of `name`, for looking up an action by the string a
+    /// caller (e.g. the overlay's JS side) addresses it with.
+    pub fn from_name(name: &str) -> Option<Self> {
+        ACTION_BINDINGS
+            .iter()
+            .map(|&(_, action)| action)
+            .find(|action| action.name() == name)
+    }
+
+    /// The config key name this action is stored under, e.g. `"start_key"`.
+    fn config_name(self) -> &'static str {
+        ACTION_BINDINGS
+            .iter()
+            .find(|&&(_, action)| action == self)
+            .map(|&(name, _)| name)
+            .expect("every Action has an ACTION_BINDINGS entry")
+    }
+}
+
+/// Resolve every bound action to its configured `HotKey`, skipping any whose
+/// key string fails to parse.
+pub fn registered_hotkeys() -> Vec<(Action, HotKey)> {
+    ACTION_BINDINGS
+        .iter()
+        .filter_map(|&(key_name, action)| {
+            get_key(key_name)
+                .and_then(|k| string_to_hotkey(&k))
+                .map(|hotkey| (action, hotkey))
+        })
+        .collect()
+}
+
 /// Global configuration state
 static CONFIG: Lazy<Arc<RwLock<HashMap<String, String>>>> = Lazy::new(|| {
     Arc::new(RwLock::new(load_config_from_file()))
@@ -80,29 +156,26 @@ pub fn key_to_str(key: &str) -> String {
     key.to_uppercase()
 }
 
-/// Resolve a key name string to a validated key string
-pub fn resolve_key(key_name: &str) -> Option<String> {
-    if key_name.is_empty() {
-        return None;
-    }
-    
+/// Valid special (non-single-character) keys, including the modifier tokens
+/// accepted as part of a `+`-delimited chord.
+const SPECIAL_KEYS: &[&str] = &[
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+    "ESC", "ESCAPE", "ENTER", "RETURN", "SPACE", "TAB", "BACKSPACE",
+    "UP", "DOWN", "LEFT", "RIGHT",
+    "HOME", "END", "PAGEUP", "PAGEDOWN", "INSERT", "DELETE",
+    "SHIFT", "CTRL", "CONTROL", "ALT", "WIN", "WINDOWS",
+    "CAPSLOCK", "NUMLOCK", "SCROLLLOCK",
+    "PRINT", "PRINTSCREEN", "PAUSE",
+];
+
+/// Resolve a single (non-chord) key token to a validated key string.
+fn resolve_single_key(key_name: &str) -> Option<String> {
     let key_upper = key_name.trim().to_uppercase();
-    
-    // Valid special keys
-    let special_keys = [
-        "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
-        "ESC", "ESCAPE", "ENTER", "RETURN", "SPACE", "TAB", "BACKSPACE",
-        "UP", "DOWN", "LEFT", "RIGHT",
-        "HOME", "END", "PAGEUP", "PAGEDOWN", "INSERT", "DELETE",
-        "SHIFT", "CTRL", "CONTROL", "ALT", "WIN", "WINDOWS",
-        "CAPSLOCK", "NUMLOCK", "SCROLLLOCK",
-        "PRINT", "PRINTSCREEN", "PAUSE",
-    ];
-    
-    if special_keys.contains(&key_upper.as_str()) {
+
+    if SPECIAL_KEYS.contains(&key_upper.as_str()) {
         return Some(key_upper);
     }
-    
+
     // Single character keys (letters and digits)
     if key_upper.len() == 1 {
         let c = key_upper.chars().next().unwrap();
@@ -110,33 +183,103 @@ pub fn resolve_key(key_name: &str) -> Option<String> {
             return Some(key_upper);
         }
     }
-    
+
     None
 }
 
-/// Get start and stop keys
-pub fn get_keys() -> (String, String) {
-    let config = load_config();
-    let start = config.get("start_key").cloned().unwrap_or_else(|| "F9".to_string());
-    let stop = config.get("stop_key").cloned().unwrap_or_else(|| "F10".to_string());
-    (start, stop)
+/// Map a modifier token to its `global_hotkey` flag.
+fn modifier_flag(token: &str) -> Option<Modifiers> {
+    match token {
+        "CTRL" | "CONTROL" => Some(Modifiers::CONTROL),
+        "ALT" => Some(Modifiers::ALT),
+        "SHIFT" => Some(Modifiers::SHIFT),
+        "WIN" | "WINDOWS" => Some(Modifiers::SUPER),
+        _ => None,
+    }
 }
 
-/// Set start and stop keys
-pub fn set_keys(start_key: &str, stop_key: &str) -> Result<(), String> {
-    if resolve_key(start_key).is_none() {
-        return Err(format!("Invalid start key: {}", start_key));
+/// Resolve a key name string to a validated key string. Accepts either a bare
+/// key (`"F9"`) or a `+`-delimited chord (`"Ctrl+F9"`, `"Alt+Shift+M"`): a
+/// leading run of modifier tokens (CTRL/ALT/SHIFT/WIN) followed by exactly
+/// one non-modifier key.
+pub fn resolve_key(key_name: &str) -> Option<String> {
+    if key_name.is_empty() {
+        return None;
     }
-    if resolve_key(stop_key).is_none() {
-        return Err(format!("Invalid stop key: {}", stop_key));
+
+    let tokens: Vec<&str> = key_name
+        .split('+')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    let (&main_token, modifier_tokens) = tokens.split_last()?;
+
+    let mut resolved_modifiers = Vec::with_capacity(modifier_tokens.len());
+    for token in modifier_tokens {
+        let token_upper = token.to_uppercase();
+        modifier_flag(&token_upper)?;
+        resolved_modifiers.push(token_upper);
     }
-    
+
+    let resolved_main = resolve_single_key(main_token)?;
+    if modifier_flag(&resolved_main).is_some() {
+        // The final token must be the "real" key, not another modifier.
+        return None;
+    }
+
+    if resolved_modifiers.is_empty() {
+        Some(resolved_main)
+    } else {
+        Some(format!("{}+{}", resolved_modifiers.join("+"), resolved_main))
+    }
+}
+
+/// Build a `global_hotkey::hotkey::HotKey` from a bare key or `+`-delimited
+/// chord string (e.g. `"Ctrl+F9"`).
+pub fn string_to_hotkey(key: &str) -> Option<HotKey> {
+    let resolved = resolve_key(key)?;
+    let tokens: Vec<&str> = resolved.split('+').collect();
+    let (&main_token, modifier_tokens) = tokens.split_last()?;
+
+    let mut modifiers = Modifiers::empty();
+    for token in modifier_tokens {
+        modifiers |= modifier_flag(token)?;
+    }
+
+    let code = string_to_code(main_token)?;
+    let modifiers = if modifier_tokens.is_empty() {
+        None
+    } else {
+        Some(modifiers)
+    };
+
+    Some(HotKey::new(modifiers, code))
+}
+
+/// Get the key currently bound to `action`.
+pub fn get_binding(action: Action) -> String {
+    get_key(action.config_name()).unwrap_or_default()
+}
+
+/// Rebind `action` to `key_str`, validated through `resolve_key`, and
+/// persist the whole keybind map atomically.
+pub fn set_binding(action: Action, key_str: &str) -> Result<String, String> {
+    let resolved = resolve_key(key_str).ok_or_else(|| format!("Invalid key: {}", key_str))?;
+
     let mut config = load_config();
-    config.insert("start_key".to_string(), start_key.to_uppercase());
-    config.insert("stop_key".to_string(), stop_key.to_uppercase());
+    config.insert(action.config_name().to_string(), resolved.clone());
     save_config(&config);
-    
-    Ok(())
+
+    Ok(resolved)
+}
+
+/// Every action paired with its currently bound key, e.g. for an editable
+/// keybind table.
+pub fn all_bindings() -> Vec<(Action, String)> {
+    ACTION_BINDINGS
+        .iter()
+        .map(|&(_, action)| (action, get_binding(action)))
+        .collect()
 }
 
 /// Get any key from config by name
@@ -156,11 +299,13 @@ pub fn set_key(name: &str, key_value: &str) -> Result<(), String> {
         return Err(format!("Invalid setting name: {}", name));
     }
     
-    let key_str = key_to_str(key_value);
+    // Prefer the canonicalized chord form so combos round-trip consistently;
+    // fall back to the old permissive uppercasing for values that don't parse.
+    let key_str = resolve_key(key_value).unwrap_or_else(|| key_to_str(key_value));
     let mut config = load_config();
     config.insert(name.to_string(), key_str);
     save_config(&config);
-    
+
     Ok(())
 }
 
@@ -242,9 +387,41 @@ mod tests {
     }
 
     #[test]
-    fn test_get_keys() {
-        let (start, stop) = get_keys();
-        assert!(!start.is_empty());
-        assert!(!stop.is_empty());
+    fn test_get_binding() {
+        assert!(!get_binding(Action::Start).is_empty());
+        assert!(!get_binding(Action::Stop).is_empty());
+    }
+
+    #[test]
+    fn test_action_name_roundtrips_through_from_name() {
+        for &(_, action) in ACTION_BINDINGS {
+            assert_eq!(Action::from_name(action.name()), Some(action));
+        }
+        assert_eq!(Action::from_name("NotAnAction"), None);
+    }
+
+    #[test]
+    fn test_resolve_key_chord() {
+        assert_eq!(resolve_key("Ctrl+F9"), Some("CTRL+F9".to_string()));
+        assert_eq!(
+            resolve_key("alt + shift + m"),
+            Some("ALT+SHIFT+M".to_string())
+        );
+        assert_eq!(resolve_key("Ctrl+Shift"), None);
+        assert_eq!(resolve_key("Ctrl+"), None);
+    }
+
+    #[test]
+    fn test_registered_hotkeys_covers_every_action_by_default() {
+        let hotkeys = registered_hotkeys();
+        assert_eq!(hotkeys.len(), ACTION_BINDINGS.len());
+    }
+
+    #[test]
+    fn test_string_to_hotkey_chord() {
+        let plain = string_to_hotkey("F9").expect("bare key should resolve");
+        let chord = string_to_hotkey("Ctrl+F9").expect("chord should resolve");
+        assert_ne!(plain.id(), chord.id());
+        assert!(string_to_hotkey("Ctrl+Unknown").is_none());
     }
 }
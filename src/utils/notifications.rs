@@ -0,0 +1,165 @@
+//! Native desktop notifications for events worth interrupting the user for.
+//!
+//! The dashboard's `.activity` div only shows the latest message inline, so
+//! it's easy to miss a rare catch or an auto-stop while the window isn't
+//! focused. This raises an OS-native toast via `notify-rust` for a short list
+//! of configured triggers - a catch at or above a minimum rarity, an XP
+//! level-up, a broken rod, a recovery sequence starting/succeeding, and the
+//! bot auto-stopping on error - gated by the master `notifications` toggle
+//! `StatsApi` exposes through the dashboard's IPC bridge, plus a per-trigger
+//! opt-in stored under the `notify_triggers` settings key so someone running
+//! the bot on a second monitor can mute, say, recovery toasts without losing
+//! broken-rod alerts.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+
+use crate::fish::{Fish, Rarity};
+use crate::utils::path::get_data_dir;
+
+/// Catches at or above this rarity trigger a toast.
+const MIN_NOTIFY_RARITY: Rarity = Rarity::Rare;
+
+/// A toast fires every time total XP crosses a multiple of this.
+const XP_LEVEL_INTERVAL: i32 = 1000;
+
+fn rarity_rank(rarity: Rarity) -> u8 {
+    match rarity {
+        Rarity::Common => 0,
+        Rarity::Rare => 1,
+        Rarity::Mythical => 2,
+    }
+}
+
+/// Whether notifications are enabled, read straight from the dashboard's
+/// settings file - on by default, matching `StatsApi`'s other toggles.
+fn notifications_enabled() -> bool {
+    let settings_file = get_data_dir().join("config").join("settings.json");
+    let Ok(content) = fs::read_to_string(&settings_file) else {
+        return true;
+    };
+    let Ok(settings) = serde_json::from_str::<HashMap<String, String>>(&content) else {
+        return true;
+    };
+    settings
+        .get("notifications")
+        .map(|s| s == "true")
+        .unwrap_or(true)
+}
+
+/// Per-trigger opt-in recorded under `notify_triggers` as a JSON object
+/// (trigger key -> enabled), e.g. `{"recovery_started": false}`. A trigger
+/// missing from the map defaults to enabled - the same fail-open default
+/// `bot_modules::ModuleRegistry` uses for an unknown module name.
+fn trigger_enabled(trigger: &str) -> bool {
+    let settings_file = get_data_dir().join("config").join("settings.json");
+    let Ok(content) = fs::read_to_string(&settings_file) else {
+        return true;
+    };
+    let Ok(settings) = serde_json::from_str::<HashMap<String, String>>(&content) else {
+        return true;
+    };
+    let Some(raw) = settings.get("notify_triggers") else {
+        return true;
+    };
+    let Ok(triggers) = serde_json::from_str::<HashMap<String, bool>>(raw) else {
+        return true;
+    };
+    triggers.get(trigger).copied().unwrap_or(true)
+}
+
+/// Whether a given trigger should fire, combining the master toggle with its
+/// own opt-in.
+fn notify_enabled(trigger: &str) -> bool {
+    notifications_enabled() && trigger_enabled(trigger)
+}
+
+fn send(summary: &str, body: &str) {
+    if let Err(e) = notify_rust::Notification::new()
+        .summary(summary)
+        .body(body)
+        .show()
+    {
+        tracing::warn!("[NOTIFY] Failed to show notification: {}", e);
+    }
+}
+
+/// Fire a toast for a catch at or above `MIN_NOTIFY_RARITY`.
+pub fn maybe_notify_catch(fish: &Fish) {
+    if !notify_enabled("catch") || rarity_rank(fish.rarity) < rarity_rank(MIN_NOTIFY_RARITY) {
+        return;
+    }
+    send(
+        "Rare catch!",
+        &format!("{} ({}, +{} XP)", fish.name, fish.rarity, fish.xp),
+    );
+}
+
+/// Fire a toast each time total XP crosses a multiple of `XP_LEVEL_INTERVAL`.
+pub fn maybe_notify_level_up(previous_xp: i32, current_xp: i32) {
+    if !notify_enabled("level_up") {
+        return;
+    }
+    let previous_level = previous_xp / XP_LEVEL_INTERVAL;
+    let current_level = current_xp / XP_LEVEL_INTERVAL;
+    if current_level > previous_level {
+        send(
+            "Level up!",
+            &format!("Reached {} XP", current_level * XP_LEVEL_INTERVAL),
+        );
+    }
+}
+
+/// Fire a toast when the bot stops itself due to an error condition, as
+/// opposed to a user pressing the stop key - covers both a generic auto-stop
+/// and the "game window lost" case inside `handle_no_progress_loop`.
+pub fn maybe_notify_stop_on_error(reason: &str) {
+    if !notify_enabled("stop_on_error") {
+        return;
+    }
+    send("Bot stopped", reason);
+}
+
+/// Fire a toast when `main_loop` detects a broken rod and starts selecting a
+/// replacement.
+pub fn maybe_notify_broken_rod() {
+    if !notify_enabled("broken_rod") {
+        return;
+    }
+    send("Rod broken", "Selecting a new fishing rod...");
+}
+
+/// Fire a toast when `handle_no_progress_loop` begins its recovery sequence.
+pub fn maybe_notify_recovery_started() {
+    if !notify_enabled("recovery_started") {
+        return;
+    }
+    send(
+        "Recovery started",
+        "No progress detected, attempting to recover...",
+    );
+}
+
+/// Fire a toast once `handle_no_progress_loop` finds the default screen and
+/// restarts the macro. The failure path (game window lost) goes through
+/// `maybe_notify_stop_on_error` instead, since that's also where the bot
+/// actually stops.
+pub fn maybe_notify_recovery_succeeded() {
+    if !notify_enabled("recovery_succeeded") {
+        return;
+    }
+    send("Recovery succeeded", "Default screen found, macro restarted.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rarity_rank_orders_common_below_mythical() {
+        assert!(rarity_rank(Rarity::Common) < rarity_rank(Rarity::Rare));
+        assert!(rarity_rank(Rarity::Rare) < rarity_rank(Rarity::Mythical));
+    }
+}
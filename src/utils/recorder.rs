@@ -0,0 +1,212 @@
+//! Session event recording for offline debugging and replay
+//!
+//! `SharedBotState` only ever holds the *current* activity, stats, and
+//! detection boxes. This module keeps a short rolling history of the events
+//! that produced that state - activity transitions, catch/miss increments,
+//! and detection-box snapshots - so a run can be inspected after the fact
+//! instead of only being observable live in the UI.
+
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::bot_state::DetectionBox;
+use crate::utils::path::get_data_dir;
+
+/// Number of events kept in the in-memory ring buffer.
+const RING_BUFFER_CAPACITY: usize = 2000;
+
+/// A single recorded event, tagged with the time it occurred.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub timestamp: String,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// The kinds of events the recorder tracks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventKind {
+    /// An activity transition, e.g. `WaitingForFish` -> `FishDetected`.
+    Activity { label: String },
+    /// A successful catch, with the XP gained.
+    Catch { xp_gain: i32 },
+    /// A missed catch.
+    Miss,
+    /// A snapshot of the current detection boxes.
+    Detection { boxes: Vec<DetectionBox> },
+}
+
+/// Records timestamped events into a bounded in-memory ring buffer, optionally
+/// flushing each one as a JSON-lines entry to disk for later replay.
+pub struct Recorder {
+    events: RwLock<VecDeque<RecordedEvent>>,
+    flush_enabled: RwLock<bool>,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Self {
+            events: RwLock::new(VecDeque::with_capacity(RING_BUFFER_CAPACITY)),
+            flush_enabled: RwLock::new(false),
+        }
+    }
+
+    /// Enable or disable flushing recorded events to `recording_path()` as
+    /// they happen. The in-memory ring buffer is always kept regardless.
+    pub fn set_flush_enabled(&self, enabled: bool) {
+        *self.flush_enabled.write() = enabled;
+    }
+
+    /// Record an event, evicting the oldest entry if the ring buffer is full.
+    pub fn record(&self, kind: EventKind) {
+        let event = RecordedEvent {
+            timestamp: Utc::now().to_rfc3339(),
+            kind,
+        };
+
+        {
+            let mut events = self.events.write();
+            if events.len() >= RING_BUFFER_CAPACITY {
+                events.pop_front();
+            }
+            events.push_back(event.clone());
+        }
+
+        if *self.flush_enabled.read() {
+            self.append_to_disk(&event);
+        }
+    }
+
+    /// Current contents of the ring buffer, oldest first.
+    pub fn events(&self) -> Vec<RecordedEvent> {
+        self.events.read().iter().cloned().collect()
+    }
+
+    /// Drop all recorded events from the ring buffer.
+    pub fn clear(&self) {
+        self.events.write().clear();
+    }
+
+    fn append_to_disk(&self, event: &RecordedEvent) {
+        let path = recording_path();
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        if let Ok(line) = serde_json::to_string(event) {
+            if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+    }
+}
+
+/// Path to the JSON-lines recording file.
+fn recording_path() -> PathBuf {
+    get_data_dir().join("logs").join("recording.jsonl")
+}
+
+/// Load a recording previously written by `Recorder::append_to_disk`. Lines
+/// that fail to parse are skipped so a truncated or partially-written file
+/// can still be replayed up to the point of corruption.
+pub fn load_recording(path: &Path) -> Vec<RecordedEvent> {
+    let file = match fs::File::open(path) {
+        Ok(f) => f,
+        Err(_) => return Vec::new(),
+    };
+
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+/// Replay a recording by invoking `on_event` for each event in order. Used for
+/// offline debugging of why a minigame failed - e.g. feeding the detection
+/// boxes back through the overlay renderer and the activity labels through a
+/// console to step through a past run.
+pub fn replay(events: &[RecordedEvent], mut on_event: impl FnMut(&RecordedEvent)) {
+    for event in events {
+        on_event(event);
+    }
+}
+
+/// Global recorder instance.
+pub static RECORDER: Lazy<Recorder> = Lazy::new(Recorder::new);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_read_back() {
+        let recorder = Recorder::new();
+        recorder.record(EventKind::Activity {
+            label: "WaitingForFish".to_string(),
+        });
+        recorder.record(EventKind::Catch { xp_gain: 10 });
+
+        let events = recorder.events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1].kind, EventKind::Catch { xp_gain: 10 }));
+    }
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest() {
+        let recorder = Recorder::new();
+        for _ in 0..RING_BUFFER_CAPACITY + 5 {
+            recorder.record(EventKind::Miss);
+        }
+        assert_eq!(recorder.events().len(), RING_BUFFER_CAPACITY);
+    }
+
+    #[test]
+    fn test_load_recording_skips_malformed_lines() {
+        let dir = std::env::temp_dir().join("blue_mancing_recorder_test");
+        let _ = fs::create_dir_all(&dir);
+        let path = dir.join("sample.jsonl");
+
+        let good = RecordedEvent {
+            timestamp: Utc::now().to_rfc3339(),
+            kind: EventKind::Miss,
+        };
+        let content = format!("{}\nnot json\n", serde_json::to_string(&good).unwrap());
+        fs::write(&path, content).unwrap();
+
+        let events = load_recording(&path);
+        assert_eq!(events.len(), 1);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_replay_visits_events_in_order() {
+        let events = vec![
+            RecordedEvent {
+                timestamp: "t1".to_string(),
+                kind: EventKind::Activity {
+                    label: "Idle".to_string(),
+                },
+            },
+            RecordedEvent {
+                timestamp: "t2".to_string(),
+                kind: EventKind::Catch { xp_gain: 5 },
+            },
+        ];
+
+        let mut seen = Vec::new();
+        replay(&events, |e| seen.push(e.timestamp.clone()));
+        assert_eq!(seen, vec!["t1".to_string(), "t2".to_string()]);
+    }
+}
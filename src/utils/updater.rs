@@ -3,13 +3,15 @@
 #![allow(dead_code)]
 
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use parking_lot::Mutex;
 use serde::Deserialize;
-use anyhow::{Result, Context};
+use anyhow::{Result, Context, bail};
+use sha2::{Digest, Sha256};
 
 /// Application version
 pub const APP_VERSION: &str = "v1.2.1";
@@ -17,14 +19,25 @@ pub const APP_VERSION: &str = "v1.2.1";
 /// URL to check for updates
 const LATEST_URL: &str = "https://raw.githubusercontent.com/rdsp04/bpsr-fishing/main/latest.json";
 
+/// Disables update checks entirely for development builds, so a dev running
+/// off a local build never gets nagged to update or accidentally triggers a
+/// download/install.
+pub const DEV_MODE: bool = cfg!(debug_assertions);
+
 /// Update information
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateInfo {
     pub version: String,
     pub url: String,
+    /// Expected SHA-256 digest of the installer, as a hex string. Optional
+    /// so feeds that predate this field still parse; when absent, the
+    /// download is not checksum-verified.
+    #[serde(default)]
+    pub sha256: Option<String>,
 }
 
 /// Update API for managing download progress
+#[derive(Clone)]
 pub struct UpdateApi {
     pub progress: Arc<Mutex<f32>>,
     pub downloaded_mb: Arc<Mutex<f32>>,
@@ -105,34 +118,82 @@ pub fn check_for_update_blocking() -> Option<UpdateInfo> {
     None
 }
 
-/// Download update with progress tracking
-pub async fn download_update(update_info: &UpdateInfo, api: &UpdateApi) -> Result<PathBuf> {
+/// Final and in-progress paths for the downloaded installer.
+fn update_paths() -> (PathBuf, PathBuf) {
     let temp_dir = std::env::temp_dir();
-    let temp_path = temp_dir.join("bpsr_fishing_update.exe");
-    
-    // Remove existing file if present
-    if temp_path.exists() {
-        fs::remove_file(&temp_path)?;
+    (
+        temp_dir.join("bpsr_fishing_update.exe"),
+        temp_dir.join("bpsr_fishing_update.exe.part"),
+    )
+}
+
+/// Download update with progress tracking, checksum verification, and
+/// resume support.
+///
+/// The installer streams into a `.part` file; it only becomes the canonical
+/// `bpsr_fishing_update.exe` (via an atomic rename) once fully downloaded
+/// and, when `update_info.sha256` is set, verified. A previously
+/// interrupted `.part` download is resumed with an HTTP `Range` request
+/// rather than restarted from scratch, falling back to a full re-download
+/// if the server doesn't honor it.
+pub async fn download_update(update_info: &UpdateInfo, api: &UpdateApi) -> Result<PathBuf> {
+    let (final_path, partial_path) = update_paths();
+
+    // A previous run may have already finished and left the final file in
+    // place; a fresh download should replace it, not append to it.
+    if final_path.exists() {
+        fs::remove_file(&final_path)?;
     }
-    
+
+    let resume_from = fs::metadata(&partial_path).map(|m| m.len()).unwrap_or(0);
+
     let client = reqwest::Client::new();
-    let response = client.get(&update_info.url).send().await?;
-    
-    let total_size = response.content_length().unwrap_or(0);
+    let mut request = client.get(&update_info.url);
+    if resume_from > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+    }
+    let response = request.send().await?;
+
+    let resuming = resume_from > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    if resume_from > 0 && !resuming {
+        // Server ignored the Range request - start over.
+        fs::remove_file(&partial_path).ok();
+    }
+    let already_downloaded = if resuming { resume_from } else { 0 };
+
+    let total_size = already_downloaded + response.content_length().unwrap_or(0);
     let total_mb = total_size as f32 / (1024.0 * 1024.0);
-    api.set_progress(0.0, Some(0.0), Some(total_mb));
-    
-    let mut file = File::create(&temp_path)?;
-    let mut downloaded: u64 = 0;
-    
+
+    let mut hasher = Sha256::new();
+    let mut file = if already_downloaded > 0 {
+        let mut existing = vec![0u8; already_downloaded as usize];
+        File::open(&partial_path)?.read_exact(&mut existing)?;
+        hasher.update(&existing);
+        fs::OpenOptions::new().append(true).open(&partial_path)?
+    } else {
+        File::create(&partial_path)?
+    };
+
+    let mut downloaded = already_downloaded;
+    api.set_progress(
+        if total_size > 0 {
+            (downloaded as f32 / total_size as f32) * 100.0
+        } else {
+            0.0
+        },
+        Some(downloaded as f32 / (1024.0 * 1024.0)),
+        Some(total_mb),
+    );
+
     let mut stream = response.bytes_stream();
     use futures_util::StreamExt;
-    
+
     while let Some(chunk_result) = stream.next().await {
         let chunk = chunk_result?;
         file.write_all(&chunk)?;
+        hasher.update(&chunk);
         downloaded += chunk.len() as u64;
-        
+
         let percent = if total_size > 0 {
             (downloaded as f32 / total_size as f32) * 100.0
         } else {
@@ -141,8 +202,23 @@ pub async fn download_update(update_info: &UpdateInfo, api: &UpdateApi) -> Resul
         let downloaded_mb = downloaded as f32 / (1024.0 * 1024.0);
         api.set_progress(percent, Some(downloaded_mb), Some(total_mb));
     }
-    
-    Ok(temp_path)
+    drop(file);
+
+    if let Some(expected) = &update_info.sha256 {
+        let digest: String = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+        if !digest.eq_ignore_ascii_case(expected) {
+            fs::remove_file(&partial_path).ok();
+            bail!(
+                "Update checksum mismatch: expected {}, got {}",
+                expected,
+                digest
+            );
+        }
+    }
+
+    fs::rename(&partial_path, &final_path).context("Failed to finalize downloaded update")?;
+
+    Ok(final_path)
 }
 
 /// Run the installer
@@ -155,6 +231,40 @@ pub fn run_installer(installer_path: &PathBuf) -> Result<()> {
     Ok(())
 }
 
+/// Download, verify, and run the installer for `update_info`, reporting
+/// percent-complete through `on_progress` as it goes. `download_update` and
+/// `run_installer` are both written for an async/blocking split the rest of
+/// the app doesn't share, so this spins up a short-lived Tokio runtime to
+/// bridge them for callers like the dashboard's `check_update` action that
+/// need one synchronous entry point.
+pub fn download_and_install_blocking(
+    update_info: &UpdateInfo,
+    on_progress: impl Fn(f32) + Send + Sync + 'static,
+) -> Result<()> {
+    let rt = tokio::runtime::Runtime::new().context("Failed to start update runtime")?;
+    rt.block_on(async {
+        let api = UpdateApi::new();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let reporter_api = api.clone();
+        let reporter_stop = Arc::clone(&stop);
+        let reporter = tokio::spawn(async move {
+            while !reporter_stop.load(Ordering::Relaxed) {
+                let (percent, _, _) = reporter_api.get_progress();
+                on_progress(percent);
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            }
+        });
+
+        let result = download_update(update_info, &api).await;
+        stop.store(true, Ordering::Relaxed);
+        let _ = reporter.await;
+
+        let installer_path = result?;
+        run_installer(&installer_path)
+    })
+}
+
 /// Get HTML for update progress window
 pub fn get_update_html() -> &'static str {
     r#"<!DOCTYPE html>
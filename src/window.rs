@@ -3,43 +3,221 @@
 #![allow(dead_code)]
 
 #[cfg(windows)]
-use windows::core::PCWSTR;
+use windows::core::PWSTR;
 #[cfg(windows)]
-use windows::Win32::Foundation::{HWND, RECT};
+use windows::Win32::Foundation::{BOOL, CloseHandle, HWND, LPARAM, RECT};
+#[cfg(windows)]
+use windows::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+};
 #[cfg(windows)]
 use windows::Win32::UI::WindowsAndMessaging::{
-    FindWindowW, GetWindowRect, SetForegroundWindow, ShowWindow, SW_SHOW,
+    EnumWindows, GetWindowRect, GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible,
+    SetForegroundWindow, ShowWindow, SW_SHOW,
 };
+#[cfg(windows)]
+use regex::Regex;
 
 /// Window title for Blue Protocol
 const TARGET_TITLE: &str = "Blue Protocol: Star Resonance";
 
-/// Find the Blue Protocol window
+/// A visible top-level window discovered via `EnumWindows`.
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy)]
+pub struct WindowInfo {
+    pub hwnd: HWND,
+    pub pid: u32,
+}
+
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+struct WindowCandidate {
+    info: WindowInfo,
+    title: String,
+}
+
+/// How a [`WindowQuery`] matches a window title.
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+enum TitleMatcher {
+    /// Case-insensitive substring match.
+    Substring(String),
+    Regex(Regex),
+}
+
+#[cfg(windows)]
+impl TitleMatcher {
+    fn is_match(&self, title: &str) -> bool {
+        match self {
+            TitleMatcher::Substring(needle) => {
+                title.to_lowercase().contains(&needle.to_lowercase())
+            }
+            TitleMatcher::Regex(re) => re.is_match(title),
+        }
+    }
+
+    fn is_exact(&self, title: &str) -> bool {
+        match self {
+            TitleMatcher::Substring(needle) => title.eq_ignore_ascii_case(needle),
+            TitleMatcher::Regex(_) => false,
+        }
+    }
+}
+
+/// A query for [`find_windows`]: a required title matcher plus an optional
+/// executable-name filter, so the bot can find its target window even if
+/// the exact title is localized or has drifted slightly.
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+pub struct WindowQuery {
+    title: TitleMatcher,
+    process_name: Option<String>,
+}
+
+#[cfg(windows)]
+impl WindowQuery {
+    /// Match windows whose title contains `needle` (case-insensitive).
+    pub fn title_contains(needle: impl Into<String>) -> Self {
+        Self {
+            title: TitleMatcher::Substring(needle.into()),
+            process_name: None,
+        }
+    }
+
+    /// Match windows whose title matches the regex `pattern`.
+    pub fn title_regex(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            title: TitleMatcher::Regex(Regex::new(pattern)?),
+            process_name: None,
+        })
+    }
+
+    /// Additionally require the owning process's executable name (without
+    /// path) to match `name`, case-insensitively.
+    pub fn with_process_name(mut self, name: impl Into<String>) -> Self {
+        self.process_name = Some(name.into());
+        self
+    }
+}
+
+/// Enumerate all visible top-level windows.
+#[cfg(windows)]
+fn enum_windows() -> Vec<WindowCandidate> {
+    unsafe extern "system" fn enum_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let candidates = &mut *(lparam.0 as *mut Vec<WindowCandidate>);
+
+        if IsWindowVisible(hwnd).as_bool() {
+            let mut buf = [0u16; 512];
+            let len = GetWindowTextW(hwnd, &mut buf);
+            if len > 0 {
+                let title = String::from_utf16_lossy(&buf[..len as usize]);
+                if !title.is_empty() {
+                    let mut pid: u32 = 0;
+                    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+                    candidates.push(WindowCandidate {
+                        info: WindowInfo { hwnd, pid },
+                        title,
+                    });
+                }
+            }
+        }
+
+        BOOL(1)
+    }
+
+    let mut candidates: Vec<WindowCandidate> = Vec::new();
+    unsafe {
+        let _ = EnumWindows(
+            Some(enum_proc),
+            LPARAM(&mut candidates as *mut Vec<WindowCandidate> as isize),
+        );
+    }
+    candidates
+}
+
+/// Resolve the executable base name (e.g. `"game.exe"`) owning `pid`.
+#[cfg(windows)]
+fn process_name_for_pid(pid: u32) -> Option<String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buf = [0u16; 512];
+        let mut len = buf.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            handle,
+            PROCESS_NAME_WIN32,
+            PWSTR(buf.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(handle);
+        result.ok()?;
+        let path = String::from_utf16_lossy(&buf[..len as usize]);
+        path.rsplit(['\\', '/']).next().map(|s| s.to_string())
+    }
+}
+
+/// Score a candidate against `query`, or `None` if it doesn't match. Higher
+/// is better; an exact (case-insensitive) title match or a matching process
+/// name both rank above a bare substring/regex hit.
+#[cfg(windows)]
+fn score_candidate(query: &WindowQuery, candidate: &WindowCandidate) -> Option<u32> {
+    if !query.title.is_match(&candidate.title) {
+        return None;
+    }
+
+    let mut score = 10;
+    if query.title.is_exact(&candidate.title) {
+        score += 20;
+    }
+
+    if let Some(wanted) = &query.process_name {
+        let actual = process_name_for_pid(candidate.info.pid)?;
+        if !actual.eq_ignore_ascii_case(wanted) {
+            return None;
+        }
+        score += 15;
+    }
+
+    Some(score)
+}
+
+/// Find all visible windows matching `query`, ranked best-match first as
+/// `(window, title, score)`.
+#[cfg(windows)]
+pub fn find_windows(query: &WindowQuery) -> Vec<(WindowInfo, String, u32)> {
+    let mut matches: Vec<(WindowInfo, String, u32)> = enum_windows()
+        .into_iter()
+        .filter_map(|candidate| {
+            score_candidate(query, &candidate)
+                .map(|score| (candidate.info, candidate.title, score))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.2.cmp(&a.2));
+    matches
+}
+
+/// Find the Blue Protocol window, tolerant of minor title drift.
 #[cfg(windows)]
 pub fn find_blue_protocol_window() -> Option<HWND> {
     tracing::trace!(
-        "[WINDOW] find_blue_protocol_window() - searching for '{}'",
+        "[WINDOW] find_blue_protocol_window() - searching for windows matching '{}'",
         TARGET_TITLE
     );
-    let title_wide: Vec<u16> = TARGET_TITLE
-        .encode_utf16()
-        .chain(std::iter::once(0))
-        .collect();
-
-    unsafe {
-        let hwnd = FindWindowW(PCWSTR::null(), PCWSTR(title_wide.as_ptr())).ok()?;
-        if hwnd.0 as usize == 0 {
-            tracing::trace!("[WINDOW] Window '{}' not found (hwnd=0)", TARGET_TITLE);
-            None
-        } else {
+    let query = WindowQuery::title_contains(TARGET_TITLE);
+    let best = find_windows(&query).into_iter().next();
+    match &best {
+        Some((info, title, score)) => {
             tracing::trace!(
-                "[WINDOW] Window '{}' found with hwnd={:?}",
-                TARGET_TITLE,
-                hwnd.0
+                "[WINDOW] Best match: '{}' (hwnd={:?}, pid={}, score={})",
+                title,
+                info.hwnd.0,
+                info.pid,
+                score
             );
-            Some(hwnd)
         }
+        None => tracing::trace!("[WINDOW] No window matching '{}' found", TARGET_TITLE),
     }
+    best.map(|(info, _, _)| info.hwnd)
 }
 
 #[cfg(not(windows))]
@@ -94,24 +272,20 @@ pub fn select_window() -> Option<String> {
     }
 }
 
-/// Get window rectangle (x1, y1, x2, y2)
+/// Get window rectangle (x1, y1, x2, y2) for the best window whose title
+/// contains `title`.
 #[cfg(windows)]
 pub fn get_window_rect(title: &str) -> Option<(i32, i32, i32, i32)> {
     tracing::trace!(
         "[WINDOW] get_window_rect('{}') - getting window bounds",
         title
     );
-    let title_wide: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+    let query = WindowQuery::title_contains(title);
+    let (info, _, _) = find_windows(&query).into_iter().next()?;
 
     unsafe {
-        let hwnd = FindWindowW(PCWSTR::null(), PCWSTR(title_wide.as_ptr())).ok()?;
-        if hwnd.0 as usize == 0 {
-            tracing::debug!("[WINDOW] Window '{}' not found.", title);
-            return None;
-        }
-
         let mut rect = RECT::default();
-        if GetWindowRect(hwnd, &mut rect).is_ok() {
+        if GetWindowRect(info.hwnd, &mut rect).is_ok() {
             let result = (rect.left, rect.top, rect.right, rect.bottom);
             tracing::trace!(
                 "[WINDOW] Window rect: left={}, top={}, right={}, bottom={} ({}x{})",
@@ -144,17 +318,23 @@ mod tests {
     #[test]
     #[cfg(windows)]
     fn test_find_nonexistent_window() {
-        use windows::core::PCWSTR;
-        use windows::Win32::UI::WindowsAndMessaging::FindWindowW;
+        let query = WindowQuery::title_contains("NonExistentWindow12345");
+        assert!(find_windows(&query).is_empty());
+    }
 
-        let title_wide: Vec<u16> = "NonExistentWindow12345"
-            .encode_utf16()
-            .chain(std::iter::once(0))
-            .collect();
+    #[test]
+    #[cfg(windows)]
+    fn test_title_matcher_substring_is_case_insensitive() {
+        let matcher = TitleMatcher::Substring("blue protocol".to_string());
+        assert!(matcher.is_match("Blue Protocol: Star Resonance"));
+        assert!(!matcher.is_match("Some Other Game"));
+    }
 
-        unsafe {
-            let hwnd = FindWindowW(PCWSTR::null(), PCWSTR(title_wide.as_ptr()));
-            assert!(hwnd.is_err() || hwnd.unwrap().0 as usize == 0);
-        }
+    #[test]
+    #[cfg(windows)]
+    fn test_title_matcher_regex() {
+        let matcher = TitleMatcher::Regex(Regex::new(r"(?i)^blue protocol").unwrap());
+        assert!(matcher.is_match("Blue Protocol: Star Resonance"));
+        assert!(!matcher.is_match("Star Resonance: Blue Protocol"));
     }
 }